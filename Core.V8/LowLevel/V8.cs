@@ -43,7 +43,7 @@ public static unsafe partial class V8
     [MethodImpl(MethodImplOptions.AggressiveInlining)]
     public static void AutoEnsuresInit()
     {
-        V8VTable->auto_ensures_init();
+        V8VTable->auto_ensures_init(null);
     }
 
     /// <summary>
@@ -7,14 +7,19 @@
 using System;
 using System.Runtime.InteropServices;
 
+
 namespace Coplt.V8Core.LowLevel.Gen
 {
     internal static unsafe partial class NativeMethods
     {
         const string __DllName = "v8core";
 
+
+
+
+
         [DllImport(__DllName, EntryPoint = "coplt_v8core_get_root_vtable", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
-        public static extern RootVTable* coplt_v8core_get_root_vtable();
+        internal static extern RootVTable* coplt_v8core_get_root_vtable();
 
 
     }
@@ -31,6 +36,24 @@ namespace Coplt.V8Core.LowLevel.Gen
         public ScriptVTable* script;
         public ValueVTable* value;
         public StringVTable* @string;
+        public SymbolVTable* symbol;
+        public PrivateVTable* @private;
+        public ObjectVTable* @object;
+        public FunctionVTable* function;
+        public PromiseVTable* promise;
+        public PromiseResolverVTable* promise_resolver;
+        public IsolateHandleVTable* isolate_handle;
+        public SnapshotCreatorVTable* snapshot_creator;
+        public ScriptCompilerVTable* script_compiler;
+        public UnboundScriptVTable* unbound_script;
+        public JsonVTable* json;
+        public ValueSerializerVTable* value_serializer;
+        public ValueDeserializerVTable* value_deserializer;
+        public DateVTable* date;
+        public RegExpVTable* reg_exp;
+        public ExceptionVTable* exception;
+        public MessageVTable* message;
+        public StackTraceVTable* stack_trace;
     }
 
     [StructLayout(LayoutKind.Sequential)]
@@ -38,10 +61,12 @@ namespace Coplt.V8Core.LowLevel.Gen
     {
         public delegate* unmanaged[Cdecl]<PlatformOpaque, void> initialize_platform;
         public delegate* unmanaged[Cdecl]<void> initialize;
-        public delegate* unmanaged[Cdecl]<void> auto_ensures_init;
+        public delegate* unmanaged[Cdecl]<ByteSlice*, void> auto_ensures_init;
         public delegate* unmanaged[Cdecl]<bool> is_initialized;
         public delegate* unmanaged[Cdecl]<PlatformOpaque> current_platform;
         public delegate* unmanaged[Cdecl]<ByteSlice> version;
+        public delegate* unmanaged[Cdecl]<ByteSlice*, void> set_flags_from_string;
+        public delegate* unmanaged[Cdecl]<ByteSlice*, nuint, nuint> set_flags_from_list;
     }
 
     [StructLayout(LayoutKind.Sequential)]
@@ -110,6 +135,39 @@ namespace Coplt.V8Core.LowLevel.Gen
         [MarshalAs(UnmanagedType.U1)] public bool set_heap_limits;
         public nuint heap_limits_initial;
         public nuint heap_limits_max;
+        public byte* snapshot_blob;
+        public nuint snapshot_blob_len;
+        public void** external_references;
+        public nuint external_references_len;
+    }
+
+    /// <summary>
+    ///  Same layout as `v8::OomDetails`
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct OomDetails
+    {
+        [MarshalAs(UnmanagedType.U1)] public bool is_heap_oom;
+        public byte* detail;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct HeapStatistics
+    {
+        public nuint total_heap_size;
+        public nuint total_heap_size_executable;
+        public nuint total_physical_size;
+        public nuint total_available_size;
+        public nuint total_global_handles_size;
+        public nuint used_global_handles_size;
+        public nuint used_heap_size;
+        public nuint heap_size_limit;
+        public nuint malloced_memory;
+        public nuint external_memory;
+        public nuint peak_malloced_memory;
+        public nuint number_of_native_contexts;
+        public nuint number_of_detached_contexts;
+        [MarshalAs(UnmanagedType.U1)] public bool does_zap_garbage;
     }
 
     [StructLayout(LayoutKind.Sequential)]
@@ -119,6 +177,24 @@ namespace Coplt.V8Core.LowLevel.Gen
         public delegate* unmanaged[Cdecl]<IsolateCreateParams, OwnedIsolateOpaque> ctor;
         public delegate* unmanaged[Cdecl]<OwnedIsolateOpaque> ctor_default;
         public delegate* unmanaged[Cdecl]<OwnedIsolateOpaque*, IsolateOpaque*> deref;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, MicrotasksPolicy> get_microtasks_policy;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, MicrotasksPolicy, void> set_microtasks_policy;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, void> perform_microtask_checkpoint;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, LocalFunctionOpaque, void> enqueue_microtask;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, delegate* unmanaged[Cdecl]<HandleScopeOpaque*, FunctionCallbackInfoOpaque*, void*, void>, void*, bool> enqueue_microtask_callback;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, delegate* unmanaged[Cdecl]<HandleScopeOpaque*, PromiseRejectEvent, LocalPromiseOpaque, LocalValueOpaque, void*, void>, void*, void> set_promise_reject_callback;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalMessageOpaque, LocalValueOpaque, void*, void>, void*, bool> set_message_listener;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, void> remove_message_listener;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, bool, int, void> set_capture_stack_trace_for_uncaught_exceptions;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, delegate* unmanaged[Cdecl]<void*, nuint, nuint, nuint>, void*, void> add_near_heap_limit_callback;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, delegate* unmanaged[Cdecl]<void*, nuint, nuint, nuint>, nuint, void> remove_near_heap_limit_callback;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, delegate* unmanaged[Cdecl]<byte*, OomDetails*, void>, void> set_oom_error_handler;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, HeapStatistics*, void> get_heap_statistics;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, long, long> adjust_amount_of_external_allocated_memory;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, delegate* unmanaged[Cdecl]<void*, ByteSlice, bool>, void*, void> take_heap_snapshot;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, void> low_memory_notification;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, MemoryPressureLevel, void> memory_pressure_notification;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, GarbageCollectionType, bool> request_garbage_collection_for_testing;
     }
 
     [StructLayout(LayoutKind.Sequential)]
@@ -133,6 +209,7 @@ namespace Coplt.V8Core.LowLevel.Gen
         public delegate* unmanaged[Cdecl]<OwnedIsolateOpaque*, HandleScopeOpaque> ctor_isolate;
         public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, IsolateOpaque*> deref_to_isolate;
         public HandleScopeImplVTable* isolate;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalValueOpaque, LocalValueOpaque> throw_exception;
     }
 
     [StructLayout(LayoutKind.Sequential)]
@@ -188,11 +265,25 @@ namespace Coplt.V8Core.LowLevel.Gen
         public nuint Item1;
     }
 
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LocalUnboundScriptOpaque
+    {
+        public void* Item1;
+    }
+
     [StructLayout(LayoutKind.Sequential)]
     internal unsafe partial struct ScriptVTable
     {
         public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalScriptOpaque*, bool> ctor_compile;
         public delegate* unmanaged[Cdecl]<LocalScriptOpaque, HandleScopeOpaque*, LocalValueOpaque*, bool> run;
+        public delegate* unmanaged[Cdecl]<LocalScriptOpaque, HandleScopeOpaque*, ulong, LocalValueOpaque*, ScriptRunStatus> run_with_timeout;
+        public delegate* unmanaged[Cdecl]<LocalScriptOpaque, HandleScopeOpaque*, LocalUnboundScriptOpaque> get_unbound_script;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct UnboundScriptVTable
+    {
+        public delegate* unmanaged[Cdecl]<LocalUnboundScriptOpaque, HandleScopeOpaque*, LocalScriptOpaque> bind_to_current_context;
     }
 
     [StructLayout(LayoutKind.Sequential)]
@@ -219,7 +310,11 @@ namespace Coplt.V8Core.LowLevel.Gen
         public delegate* unmanaged[Cdecl]<ValueOpaque*, bool> is_name;
         public delegate* unmanaged[Cdecl]<ValueOpaque*, bool> is_string;
         public delegate* unmanaged[Cdecl]<ValueOpaque*, bool> is_symbol;
+        public delegate* unmanaged[Cdecl]<ValueOpaque*, bool> is_object;
+        public delegate* unmanaged[Cdecl]<ValueOpaque*, bool> is_date;
+        public delegate* unmanaged[Cdecl]<ValueOpaque*, bool> is_reg_exp;
         public delegate* unmanaged[Cdecl]<ValueOpaque*, HandleScopeOpaque*, LocalStringOpaque*, bool> to_string;
+        public delegate* unmanaged[Cdecl]<ValueOpaque*, HandleScopeOpaque*, LocalObjectOpaque*, bool> to_object;
     }
 
     [StructLayout(LayoutKind.Sequential)]
@@ -236,6 +331,394 @@ namespace Coplt.V8Core.LowLevel.Gen
         public delegate* unmanaged[Cdecl]<LocalStringOpaque, IsolateOpaque*, CharSliceMut*, nuint, int, nuint> read_utf16;
     }
 
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LocalSymbolOpaque
+    {
+        public void* Item1;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LocalPrivateOpaque
+    {
+        public void* Item1;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct SymbolVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> ctor;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalSymbolOpaque> ctor_description;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalSymbolOpaque> for_key;
+        public delegate* unmanaged[Cdecl]<LocalSymbolOpaque, HandleScopeOpaque*, LocalValueOpaque> description;
+        public delegate* unmanaged[Cdecl]<LocalSymbolOpaque, LocalValueOpaque> as_value;
+        public delegate* unmanaged[Cdecl]<LocalValueOpaque, LocalSymbolOpaque*, bool> from_value;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> async_iterator;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> has_instance;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> is_concat_spreadable;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> iterator;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> match_;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> replace;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> search;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> split;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> to_primitive;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> to_string_tag;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalSymbolOpaque> unscopables;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct PrivateVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalPrivateOpaque> ctor;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalPrivateOpaque> ctor_name;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalPrivateOpaque> for_key;
+        public delegate* unmanaged[Cdecl]<LocalPrivateOpaque, HandleScopeOpaque*, LocalValueOpaque> name;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LocalObjectOpaque
+    {
+        public void* Item1;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ObjectVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalObjectOpaque> ctor;
+        public delegate* unmanaged[Cdecl]<LocalObjectOpaque, LocalValueOpaque> as_value;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalValueOpaque, LocalValueOpaque*, LocalValueOpaque*, nuint, LocalObjectOpaque*, bool> ctor_with_properties;
+        public delegate* unmanaged[Cdecl]<LocalObjectOpaque, HandleScopeOpaque*, LocalValueOpaque*, LocalValueOpaque*, nuint, nuint*, bool> get_own_properties;
+        public delegate* unmanaged[Cdecl]<LocalObjectOpaque, HandleScopeOpaque*, LocalPrivateOpaque, LocalValueOpaque*, bool> get_private;
+        public delegate* unmanaged[Cdecl]<LocalObjectOpaque, HandleScopeOpaque*, LocalPrivateOpaque, LocalValueOpaque, OptionBool> set_private;
+        public delegate* unmanaged[Cdecl]<LocalObjectOpaque, HandleScopeOpaque*, LocalPrivateOpaque, OptionBool> has_private;
+        public delegate* unmanaged[Cdecl]<LocalObjectOpaque, HandleScopeOpaque*, LocalPrivateOpaque, OptionBool> delete_private;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LocalFunctionOpaque
+    {
+        public void* Item1;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct FunctionCallbackInfoOpaque
+    {
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct FunctionVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, delegate* unmanaged[Cdecl]<HandleScopeOpaque*, FunctionCallbackInfoOpaque*, void*, void>, void*, LocalFunctionOpaque*, bool> ctor;
+        public delegate* unmanaged[Cdecl]<LocalFunctionOpaque, LocalValueOpaque> as_value;
+        public delegate* unmanaged[Cdecl]<LocalValueOpaque, LocalFunctionOpaque*, bool> from_value;
+        public delegate* unmanaged[Cdecl]<LocalFunctionOpaque, HandleScopeOpaque*, LocalValueOpaque, LocalValueOpaque*, nuint, LocalValueOpaque*, bool> call;
+        public delegate* unmanaged[Cdecl]<FunctionCallbackInfoOpaque*, int> info_length;
+        public delegate* unmanaged[Cdecl]<FunctionCallbackInfoOpaque*, int, LocalValueOpaque> info_get;
+        public delegate* unmanaged[Cdecl]<FunctionCallbackInfoOpaque*, LocalObjectOpaque> info_this;
+        public delegate* unmanaged[Cdecl]<FunctionCallbackInfoOpaque*, LocalValueOpaque> info_new_target;
+        public delegate* unmanaged[Cdecl]<FunctionCallbackInfoOpaque*, LocalValueOpaque, void> info_set_return_value;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LocalPromiseOpaque
+    {
+        public void* Item1;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LocalPromiseResolverOpaque
+    {
+        public void* Item1;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct PromiseVTable
+    {
+        public delegate* unmanaged[Cdecl]<LocalPromiseOpaque, LocalValueOpaque> as_value;
+        public delegate* unmanaged[Cdecl]<LocalValueOpaque, LocalPromiseOpaque*, bool> from_value;
+        public delegate* unmanaged[Cdecl]<LocalPromiseOpaque, PromiseState> state;
+        public delegate* unmanaged[Cdecl]<LocalPromiseOpaque, bool> has_handler;
+        public delegate* unmanaged[Cdecl]<LocalPromiseOpaque, HandleScopeOpaque*, LocalValueOpaque> result;
+        public delegate* unmanaged[Cdecl]<LocalPromiseOpaque, HandleScopeOpaque*, LocalFunctionOpaque, LocalPromiseOpaque*, bool> then;
+        public delegate* unmanaged[Cdecl]<LocalPromiseOpaque, HandleScopeOpaque*, LocalFunctionOpaque, LocalFunctionOpaque, LocalPromiseOpaque*, bool> then2;
+        public delegate* unmanaged[Cdecl]<LocalPromiseOpaque, HandleScopeOpaque*, LocalFunctionOpaque, LocalPromiseOpaque*, bool> @catch;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct PromiseResolverVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalPromiseResolverOpaque*, bool> ctor;
+        public delegate* unmanaged[Cdecl]<LocalPromiseResolverOpaque, HandleScopeOpaque*, LocalPromiseOpaque> get_promise;
+        public delegate* unmanaged[Cdecl]<LocalPromiseResolverOpaque, HandleScopeOpaque*, LocalValueOpaque, OptionBool> resolve;
+        public delegate* unmanaged[Cdecl]<LocalPromiseResolverOpaque, HandleScopeOpaque*, LocalValueOpaque, OptionBool> reject;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct IsolateHandleOpaque
+    {
+        public nuint Item1;
+    }
+
+    /// <summary>
+    ///  All functions except `ctor` can be called from any thread
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct IsolateHandleVTable
+    {
+        public delegate* unmanaged[Cdecl]<IsolateHandleOpaque, void> drop;
+        public delegate* unmanaged[Cdecl]<IsolateHandleOpaque*, IsolateHandleOpaque> clone;
+        public delegate* unmanaged[Cdecl]<OwnedIsolateOpaque*, IsolateHandleOpaque> ctor;
+        public delegate* unmanaged[Cdecl]<IsolateHandleOpaque*, bool> terminate_execution;
+        public delegate* unmanaged[Cdecl]<IsolateHandleOpaque*, bool> cancel_terminate_execution;
+        public delegate* unmanaged[Cdecl]<IsolateHandleOpaque*, bool> is_execution_terminating;
+        public delegate* unmanaged[Cdecl]<IsolateHandleOpaque*, delegate* unmanaged[Cdecl]<IsolateOpaque*, void*, void>, void*, bool> request_interrupt;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct StartupDataOpaque
+    {
+        public nuint Item1;
+        public nuint Item2;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct SnapshotCreatorOpaque
+    {
+        public nuint Item1;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct SnapshotCreatorVTable
+    {
+        public delegate* unmanaged[Cdecl]<void**, nuint, SnapshotCreatorOpaque> ctor;
+        public delegate* unmanaged[Cdecl]<SnapshotCreatorOpaque, void> drop;
+        public delegate* unmanaged[Cdecl]<SnapshotCreatorOpaque*, OwnedIsolateOpaque*> isolate;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, LocalContextOpaque, void> set_default_context;
+        public delegate* unmanaged[Cdecl]<IsolateOpaque*, LocalContextOpaque, nuint> add_context;
+        public delegate* unmanaged[Cdecl]<SnapshotCreatorOpaque, FunctionCodeHandling, StartupDataOpaque*, bool> create_blob;
+        public delegate* unmanaged[Cdecl]<StartupDataOpaque, void> blob_drop;
+        public delegate* unmanaged[Cdecl]<StartupDataOpaque*, ByteSlice> blob_bytes;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct CachedDataOpaque
+    {
+        public nuint Item1;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ScriptCompilerVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, CompileOptions, ByteSlice*, LocalScriptOpaque*, bool*, bool> compile;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, CompileOptions, ByteSlice*, LocalUnboundScriptOpaque*, bool*, bool> compile_unbound;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalStringOpaque*, nuint, LocalObjectOpaque*, nuint, CompileOptions, ByteSlice*, LocalFunctionOpaque*, bool*, bool> compile_function;
+        public delegate* unmanaged[Cdecl]<uint> cached_data_version_tag;
+        public delegate* unmanaged[Cdecl]<LocalUnboundScriptOpaque, CachedDataOpaque*, bool> create_code_cache_unbound_script;
+        public delegate* unmanaged[Cdecl]<LocalFunctionOpaque, CachedDataOpaque*, bool> create_code_cache_function;
+        public delegate* unmanaged[Cdecl]<CachedDataOpaque, void> cached_data_drop;
+        public delegate* unmanaged[Cdecl]<CachedDataOpaque*, ByteSlice> cached_data_bytes;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct JsonVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalValueOpaque*, bool> parse;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalValueOpaque, LocalValueOpaque*, LocalStringOpaque*, bool> stringify;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ValueSerializerOpaque
+    {
+        public nuint Item1;
+    }
+
+    /// <summary>
+    ///  The serializer itself or the one passed to `write_host_object`
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ValueSerializerHelperOpaque
+    {
+        public nuint Item1;
+        public nuint Item2;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct SerializedDataOpaque
+    {
+        public nuint Item1;
+        public nuint Item2;
+        public nuint Item3;
+    }
+
+    /// <summary>
+    ///  Host callbacks used while serializing, a null callback keeps v8's default of throwing an error
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ValueSerializerDelegate
+    {
+        public void* data;
+        public delegate* unmanaged[Cdecl]<void*, HandleScopeOpaque*, LocalStringOpaque, void> throw_data_clone_error;
+        public delegate* unmanaged[Cdecl]<void*, HandleScopeOpaque*, LocalObjectOpaque, ValueSerializerHelperOpaque, OptionBool> write_host_object;
+        public delegate* unmanaged[Cdecl]<void*, HandleScopeOpaque*, LocalObjectOpaque, uint*, bool> get_shared_array_buffer_id;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ValueSerializerVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, ValueSerializerDelegate*, ValueSerializerOpaque> ctor;
+        public delegate* unmanaged[Cdecl]<ValueSerializerOpaque, void> drop;
+        public delegate* unmanaged[Cdecl]<ValueSerializerOpaque, SerializedDataOpaque> release;
+        public delegate* unmanaged[Cdecl]<ValueSerializerOpaque*, ValueSerializerHelperOpaque> helper;
+        public delegate* unmanaged[Cdecl]<ValueSerializerHelperOpaque, void> write_header;
+        public delegate* unmanaged[Cdecl]<ValueSerializerHelperOpaque, HandleScopeOpaque*, LocalValueOpaque, OptionBool> write_value;
+        public delegate* unmanaged[Cdecl]<ValueSerializerHelperOpaque, uint, void> write_uint32;
+        public delegate* unmanaged[Cdecl]<ValueSerializerHelperOpaque, ulong, void> write_uint64;
+        public delegate* unmanaged[Cdecl]<ValueSerializerHelperOpaque, double, void> write_double;
+        public delegate* unmanaged[Cdecl]<ValueSerializerHelperOpaque, ByteSlice, void> write_raw_bytes;
+        public delegate* unmanaged[Cdecl]<SerializedDataOpaque, void> data_drop;
+        public delegate* unmanaged[Cdecl]<SerializedDataOpaque*, ByteSlice> data_bytes;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ValueDeserializerOpaque
+    {
+        public nuint Item1;
+    }
+
+    /// <summary>
+    ///  The deserializer itself or the one passed to `read_host_object`
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ValueDeserializerHelperOpaque
+    {
+        public nuint Item1;
+        public nuint Item2;
+    }
+
+    /// <summary>
+    ///  Host callbacks used while deserializing, a null callback keeps v8's default of throwing an error
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ValueDeserializerDelegate
+    {
+        public void* data;
+        public delegate* unmanaged[Cdecl]<void*, HandleScopeOpaque*, ValueDeserializerHelperOpaque, LocalObjectOpaque*, bool> read_host_object;
+        public delegate* unmanaged[Cdecl]<void*, HandleScopeOpaque*, uint, LocalObjectOpaque*, bool> get_shared_array_buffer_from_id;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ValueDeserializerVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, ValueDeserializerDelegate*, ByteSlice, ValueDeserializerOpaque> ctor;
+        public delegate* unmanaged[Cdecl]<ValueDeserializerOpaque, void> drop;
+        public delegate* unmanaged[Cdecl]<ValueDeserializerOpaque*, bool, void> set_supports_legacy_wire_format;
+        public delegate* unmanaged[Cdecl]<ValueDeserializerOpaque*, ValueDeserializerHelperOpaque> helper;
+        public delegate* unmanaged[Cdecl]<ValueDeserializerHelperOpaque, HandleScopeOpaque*, OptionBool> read_header;
+        public delegate* unmanaged[Cdecl]<ValueDeserializerHelperOpaque, HandleScopeOpaque*, LocalValueOpaque*, bool> read_value;
+        public delegate* unmanaged[Cdecl]<ValueDeserializerHelperOpaque, uint*, bool> read_uint32;
+        public delegate* unmanaged[Cdecl]<ValueDeserializerHelperOpaque, ulong*, bool> read_uint64;
+        public delegate* unmanaged[Cdecl]<ValueDeserializerHelperOpaque, double*, bool> read_double;
+        public delegate* unmanaged[Cdecl]<ValueDeserializerHelperOpaque, nuint, ByteSlice*, bool> read_raw_bytes;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LocalDateOpaque
+    {
+        public void* Item1;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct DateVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, double, LocalDateOpaque*, bool> ctor;
+        public delegate* unmanaged[Cdecl]<LocalDateOpaque, LocalValueOpaque> as_value;
+        public delegate* unmanaged[Cdecl]<LocalValueOpaque, LocalDateOpaque*, bool> from_value;
+        public delegate* unmanaged[Cdecl]<LocalDateOpaque, double> value_of;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LocalRegExpOpaque
+    {
+        public void* Item1;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct RegExpVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalStringOpaque, LocalRegExpOpaque*, bool> ctor;
+        public delegate* unmanaged[Cdecl]<LocalRegExpOpaque, LocalValueOpaque> as_value;
+        public delegate* unmanaged[Cdecl]<LocalValueOpaque, LocalRegExpOpaque*, bool> from_value;
+        public delegate* unmanaged[Cdecl]<LocalRegExpOpaque, HandleScopeOpaque*, LocalStringOpaque, LocalValueOpaque*, bool> exec;
+        public delegate* unmanaged[Cdecl]<LocalRegExpOpaque, HandleScopeOpaque*, LocalStringOpaque*, bool> get_source;
+        public delegate* unmanaged[Cdecl]<LocalRegExpOpaque, HandleScopeOpaque*, LocalStringOpaque*, bool> get_flags;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LocalMessageOpaque
+    {
+        public void* Item1;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct LocalStackTraceOpaque
+    {
+        public void* Item1;
+    }
+
+    /// <summary>
+    ///  A frame of a captured stack trace, the names are null when v8 has none for the frame
+    /// </summary>
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct StackFrame
+    {
+        public LocalStringOpaque function_name;
+        public LocalStringOpaque script_name;
+        public LocalStringOpaque script_name_or_source_url;
+        public nuint script_id;
+        public nuint line_number;
+        public nuint column;
+        [MarshalAs(UnmanagedType.U1)] public bool is_eval;
+        [MarshalAs(UnmanagedType.U1)] public bool is_constructor;
+        [MarshalAs(UnmanagedType.U1)] public bool is_wasm;
+        [MarshalAs(UnmanagedType.U1)] public bool is_user_javascript;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct ExceptionVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalValueOpaque> error;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalValueOpaque> range_error;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalValueOpaque> reference_error;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalValueOpaque> syntax_error;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque, LocalValueOpaque> type_error;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalValueOpaque, LocalMessageOpaque> create_message;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalValueOpaque, LocalStackTraceOpaque*, bool> get_stack_trace;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct MessageVTable
+    {
+        public delegate* unmanaged[Cdecl]<LocalMessageOpaque, HandleScopeOpaque*, LocalStringOpaque> get;
+        public delegate* unmanaged[Cdecl]<LocalMessageOpaque, HandleScopeOpaque*, LocalStringOpaque*, bool> get_source_line;
+        public delegate* unmanaged[Cdecl]<LocalMessageOpaque, HandleScopeOpaque*, LocalValueOpaque*, bool> get_script_resource_name;
+        public delegate* unmanaged[Cdecl]<LocalMessageOpaque, HandleScopeOpaque*, nuint*, bool> get_line_number;
+        public delegate* unmanaged[Cdecl]<LocalMessageOpaque, int> get_start_position;
+        public delegate* unmanaged[Cdecl]<LocalMessageOpaque, int> get_end_position;
+        public delegate* unmanaged[Cdecl]<LocalMessageOpaque, nuint> get_start_column;
+        public delegate* unmanaged[Cdecl]<LocalMessageOpaque, nuint> get_end_column;
+        public delegate* unmanaged[Cdecl]<LocalMessageOpaque, int> error_level;
+        public delegate* unmanaged[Cdecl]<LocalMessageOpaque, HandleScopeOpaque*, LocalStackTraceOpaque*, bool> get_stack_trace;
+    }
+
+    [StructLayout(LayoutKind.Sequential)]
+    internal unsafe partial struct StackTraceVTable
+    {
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, nuint, LocalStackTraceOpaque*, bool> current_stack_trace;
+        public delegate* unmanaged[Cdecl]<HandleScopeOpaque*, LocalStringOpaque*, bool> current_script_name_or_source_url;
+        public delegate* unmanaged[Cdecl]<LocalStackTraceOpaque, nuint> get_frame_count;
+        public delegate* unmanaged[Cdecl]<LocalStackTraceOpaque, HandleScopeOpaque*, nuint, StackFrame*, bool> get_frame;
+        public delegate* unmanaged[Cdecl]<LocalStackTraceOpaque, HandleScopeOpaque*, StackFrame*, nuint, nuint> get_frames;
+    }
+
 
     internal enum OptionBool : byte
     {
@@ -244,6 +727,63 @@ namespace Coplt.V8Core.LowLevel.Gen
         True = 1,
     }
 
+    internal enum MicrotasksPolicy : uint
+    {
+        Explicit = 0,
+        Auto = 2,
+    }
+
+    internal enum MemoryPressureLevel : uint
+    {
+        None = 0,
+        Moderate = 1,
+        Critical = 2,
+    }
+
+    internal enum GarbageCollectionType : uint
+    {
+        Full,
+        Minor,
+    }
+
+    internal enum ScriptRunStatus : uint
+    {
+        Success,
+        Exception,
+        TimedOut,
+        /// <summary>
+        ///  Terminated by something other than the timeout, e.g. `IsolateHandle::terminate_execution`
+        /// </summary>
+        Terminated,
+    }
+
+    internal enum PromiseState : uint
+    {
+        Pending,
+        Fulfilled,
+        Rejected,
+    }
+
+    internal enum PromiseRejectEvent : uint
+    {
+        PromiseRejectWithNoHandler,
+        PromiseHandlerAddedAfterReject,
+        PromiseRejectAfterResolved,
+        PromiseResolveAfterResolved,
+    }
+
+    internal enum FunctionCodeHandling : uint
+    {
+        Clear,
+        Keep,
+    }
+
+    internal enum CompileOptions : uint
+    {
+        NoCompileOptions,
+        ConsumeCodeCache,
+        EagerCompile,
+    }
+
 
 }
-    
\ No newline at end of file
@@ -10,6 +10,19 @@ fn main() {
         .input_extern_file("./src/script.rs")
         .input_extern_file("./src/value.rs")
         .input_extern_file("./src/string.rs")
+        .input_extern_file("./src/symbol.rs")
+        .input_extern_file("./src/object.rs")
+        .input_extern_file("./src/function.rs")
+        .input_extern_file("./src/promise.rs")
+        .input_extern_file("./src/isolate_handle.rs")
+        .input_extern_file("./src/snapshot.rs")
+        .input_extern_file("./src/script_compiler.rs")
+        .input_extern_file("./src/json.rs")
+        .input_extern_file("./src/value_serializer.rs")
+        .input_extern_file("./src/value_deserializer.rs")
+        .input_extern_file("./src/date.rs")
+        .input_extern_file("./src/regexp.rs")
+        .input_extern_file("./src/exception.rs")
         .csharp_dll_name("v8core")
         .csharp_namespace("Coplt.V8Core.LowLevel.Gen")
         .generate_csharp_file("../Core.V8/LowLevel/gen/v8.g.cs")
@@ -14,12 +14,55 @@ pub const CONTEXT_VTABLE: ContextVTable = ContextVTable {
     ctor: impls::context_new,
 };
 
+/// Creates a context and captures its intrinsics before any script can run in it
+pub(crate) fn new_context<'s>(scope: &mut v8::HandleScope<'s, ()>) -> v8::Local<'s, v8::Context> {
+    let ctx = v8::Context::new(scope);
+    let scope = &mut v8::ContextScope::new(scope, ctx);
+    if let Some(intrinsics) = Intrinsics::capture(scope) {
+        ctx.set_slot(scope, intrinsics);
+    }
+    ctx
+}
+
+/// Builtins captured when the context is created, before any script can replace the globals.
+/// The handles are weak because the native context keeps the builtins alive for its own lifetime,
+/// a strong handle stored in the context's slot would keep the context alive forever.
+/// Weak handles cannot be snapshotted, so the slot is cleared when the context is added to a
+/// snapshot and captured again on first use.
+pub(crate) struct Intrinsics {
+    reg_exp: v8::Weak<v8::Function>,
+}
+
+impl Intrinsics {
+    fn capture(scope: &mut v8::HandleScope) -> Option<Self> {
+        let global = scope.get_current_context().global(scope);
+        let key = v8::String::new(scope, "RegExp")?;
+        let reg_exp = v8::Local::<v8::Function>::try_from(global.get(scope, key.into())?).ok()?;
+        Some(Self {
+            reg_exp: v8::Weak::new(scope, reg_exp),
+        })
+    }
+
+    /// The intrinsic `RegExp` constructor of the current context. Contexts not created by
+    /// `new_context`, or cleared for a snapshot, capture it from the global object on first use.
+    pub(crate) fn reg_exp(
+        scope: &mut v8::HandleScope<'static>,
+    ) -> Option<v8::Local<'static, v8::Function>> {
+        let context = scope.get_current_context();
+        if context.get_slot::<Intrinsics>(scope).is_none() {
+            let intrinsics = Self::capture(scope)?;
+            context.set_slot(scope, intrinsics);
+        }
+        let reg_exp = context.get_slot::<Intrinsics>(scope)?.reg_exp.clone();
+        reg_exp.to_local(scope)
+    }
+}
+
 mod impls {
     use super::*;
 
     pub unsafe extern "C" fn context_new(scope: *mut HandleScopeOpaque) -> LocalContextOpaque {
         let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
-        let ctx = v8::Context::new(scope);
-        ctx.into()
+        new_context(scope).into()
     }
 }
@@ -0,0 +1,71 @@
+use crate::{impl_transmute_cast, HandleScopeOpaque, LocalValueOpaque};
+use std::ffi::c_void;
+
+#[repr(C)]
+pub struct LocalDateOpaque(*mut c_void);
+
+impl_transmute_cast! { LocalDateOpaque as v8::Local<'static, v8::Date> }
+
+#[repr(C)]
+pub struct DateVTable {
+    ctor: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        time: f64,
+        ret: *mut LocalDateOpaque,
+    ) -> bool,
+    as_value: unsafe extern "C" fn(ptr: LocalDateOpaque) -> LocalValueOpaque,
+    from_value: unsafe extern "C" fn(value: LocalValueOpaque, ret: *mut LocalDateOpaque) -> bool,
+    value_of: unsafe extern "C" fn(ptr: LocalDateOpaque) -> f64,
+}
+
+pub const DATE_VTABLE: DateVTable = DateVTable {
+    ctor: impls::date_new,
+    as_value: impls::date_as_value,
+    from_value: impls::date_from_value,
+    value_of: impls::date_value_of,
+};
+
+mod impls {
+    use super::*;
+
+    /// `time` is in milliseconds since the unix epoch
+    pub unsafe extern "C" fn date_new(
+        scope: *mut HandleScopeOpaque,
+        time: f64,
+        ret: *mut LocalDateOpaque,
+    ) -> bool {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        match v8::Date::new(scope, time) {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn date_as_value(ptr: LocalDateOpaque) -> LocalValueOpaque {
+        let date: v8::Local<'static, v8::Date> = ptr.into();
+        let val: v8::Local<'static, v8::Value> = date.into();
+        val.into()
+    }
+
+    pub unsafe extern "C" fn date_from_value(
+        value: LocalValueOpaque,
+        ret: *mut LocalDateOpaque,
+    ) -> bool {
+        let val: v8::Local<'static, v8::Value> = value.into();
+        match v8::Local::<v8::Date>::try_from(val) {
+            Ok(v) => {
+                *ret = v.into();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    pub unsafe extern "C" fn date_value_of(ptr: LocalDateOpaque) -> f64 {
+        let date: v8::Local<'static, v8::Date> = ptr.into();
+        date.value_of()
+    }
+}
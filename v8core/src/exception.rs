@@ -0,0 +1,463 @@
+use crate::{impl_transmute_cast, HandleScopeOpaque, LocalStringOpaque, LocalValueOpaque};
+use std::ffi::{c_int, c_void};
+
+#[repr(C)]
+pub struct LocalMessageOpaque(*mut c_void);
+
+impl_transmute_cast! { LocalMessageOpaque as v8::Local<'static, v8::Message> }
+
+#[repr(C)]
+pub struct LocalStackTraceOpaque(*mut c_void);
+
+impl_transmute_cast! { LocalStackTraceOpaque as v8::Local<'static, v8::StackTrace> }
+
+/// A frame of a captured stack trace, the names are null when v8 has none for the frame
+#[repr(C)]
+pub struct StackFrame {
+    function_name: LocalStringOpaque,
+    script_name: LocalStringOpaque,
+    script_name_or_source_url: LocalStringOpaque,
+    script_id: usize,
+    line_number: usize,
+    column: usize,
+    is_eval: bool,
+    is_constructor: bool,
+    is_wasm: bool,
+    is_user_javascript: bool,
+}
+
+impl StackFrame {
+    fn new(
+        scope: &mut v8::HandleScope<'static>,
+        frame: v8::Local<'static, v8::StackFrame>,
+    ) -> Self {
+        Self {
+            function_name: nullable(frame.get_function_name(scope)),
+            script_name: nullable(frame.get_script_name(scope)),
+            script_name_or_source_url: nullable(frame.get_script_name_or_source_url(scope)),
+            script_id: frame.get_script_id(),
+            line_number: frame.get_line_number(),
+            column: frame.get_column(),
+            is_eval: frame.is_eval(),
+            is_constructor: frame.is_constructor(),
+            is_wasm: frame.is_wasm(),
+            is_user_javascript: frame.is_user_javascript(),
+        }
+    }
+}
+
+fn nullable(value: Option<v8::Local<'static, v8::String>>) -> LocalStringOpaque {
+    // a Local is a non-null pointer, so None has the same layout as a null handle
+    unsafe {
+        std::mem::transmute::<Option<v8::Local<'static, v8::String>>, LocalStringOpaque>(value)
+    }
+}
+
+#[repr(C)]
+pub struct ExceptionVTable {
+    error: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        message: LocalStringOpaque,
+    ) -> LocalValueOpaque,
+    range_error: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        message: LocalStringOpaque,
+    ) -> LocalValueOpaque,
+    reference_error: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        message: LocalStringOpaque,
+    ) -> LocalValueOpaque,
+    syntax_error: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        message: LocalStringOpaque,
+    ) -> LocalValueOpaque,
+    type_error: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        message: LocalStringOpaque,
+    ) -> LocalValueOpaque,
+    create_message: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        exception: LocalValueOpaque,
+    ) -> LocalMessageOpaque,
+    get_stack_trace: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        exception: LocalValueOpaque,
+        ret: *mut LocalStackTraceOpaque,
+    ) -> bool,
+}
+
+pub const EXCEPTION_VTABLE: ExceptionVTable = ExceptionVTable {
+    error: impls::errors::error,
+    range_error: impls::errors::range_error,
+    reference_error: impls::errors::reference_error,
+    syntax_error: impls::errors::syntax_error,
+    type_error: impls::errors::type_error,
+    create_message: impls::exception_create_message,
+    get_stack_trace: impls::exception_get_stack_trace,
+};
+
+#[repr(C)]
+pub struct MessageVTable {
+    get: unsafe extern "C" fn(
+        ptr: LocalMessageOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalStringOpaque,
+    get_source_line: unsafe extern "C" fn(
+        ptr: LocalMessageOpaque,
+        scope: *mut HandleScopeOpaque,
+        ret: *mut LocalStringOpaque,
+    ) -> bool,
+    get_script_resource_name: unsafe extern "C" fn(
+        ptr: LocalMessageOpaque,
+        scope: *mut HandleScopeOpaque,
+        ret: *mut LocalValueOpaque,
+    ) -> bool,
+    get_line_number: unsafe extern "C" fn(
+        ptr: LocalMessageOpaque,
+        scope: *mut HandleScopeOpaque,
+        ret: *mut usize,
+    ) -> bool,
+    get_start_position: unsafe extern "C" fn(ptr: LocalMessageOpaque) -> c_int,
+    get_end_position: unsafe extern "C" fn(ptr: LocalMessageOpaque) -> c_int,
+    get_start_column: unsafe extern "C" fn(ptr: LocalMessageOpaque) -> usize,
+    get_end_column: unsafe extern "C" fn(ptr: LocalMessageOpaque) -> usize,
+    error_level: unsafe extern "C" fn(ptr: LocalMessageOpaque) -> c_int,
+    get_stack_trace: unsafe extern "C" fn(
+        ptr: LocalMessageOpaque,
+        scope: *mut HandleScopeOpaque,
+        ret: *mut LocalStackTraceOpaque,
+    ) -> bool,
+}
+
+pub const MESSAGE_VTABLE: MessageVTable = MessageVTable {
+    get: impls::message::get,
+    get_source_line: impls::message::get_source_line,
+    get_script_resource_name: impls::message::get_script_resource_name,
+    get_line_number: impls::message::get_line_number,
+    get_start_position: impls::message::get_start_position,
+    get_end_position: impls::message::get_end_position,
+    get_start_column: impls::message::get_start_column,
+    get_end_column: impls::message::get_end_column,
+    error_level: impls::message::error_level,
+    get_stack_trace: impls::message::get_stack_trace,
+};
+
+#[repr(C)]
+pub struct StackTraceVTable {
+    current_stack_trace: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        frame_limit: usize,
+        ret: *mut LocalStackTraceOpaque,
+    ) -> bool,
+    current_script_name_or_source_url:
+        unsafe extern "C" fn(scope: *mut HandleScopeOpaque, ret: *mut LocalStringOpaque) -> bool,
+    get_frame_count: unsafe extern "C" fn(ptr: LocalStackTraceOpaque) -> usize,
+    get_frame: unsafe extern "C" fn(
+        ptr: LocalStackTraceOpaque,
+        scope: *mut HandleScopeOpaque,
+        index: usize,
+        ret: *mut StackFrame,
+    ) -> bool,
+    get_frames: unsafe extern "C" fn(
+        ptr: LocalStackTraceOpaque,
+        scope: *mut HandleScopeOpaque,
+        frames: *mut StackFrame,
+        capacity: usize,
+    ) -> usize,
+}
+
+pub const STACK_TRACE_VTABLE: StackTraceVTable = StackTraceVTable {
+    current_stack_trace: impls::stack_trace::current_stack_trace,
+    current_script_name_or_source_url: impls::stack_trace::current_script_name_or_source_url,
+    get_frame_count: impls::stack_trace::get_frame_count,
+    get_frame: impls::stack_trace::get_frame,
+    get_frames: impls::stack_trace::get_frames,
+};
+
+mod impls {
+    use super::*;
+
+    pub mod errors {
+        use super::*;
+
+        macro_rules! errors {
+            { $($name:ident;)* } => {
+                $(
+                    pub unsafe extern "C" fn $name(
+                        scope: *mut HandleScopeOpaque,
+                        message: LocalStringOpaque,
+                    ) -> LocalValueOpaque {
+                        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+                        v8::Exception::$name(scope, message.into()).into()
+                    }
+                )*
+            };
+        }
+
+        errors! {
+            error;
+            range_error;
+            reference_error;
+            syntax_error;
+            type_error;
+        }
+    }
+
+    /// Reconstructs the message from the stack trace captured with the exception, or from the
+    /// current stack if it has none
+    pub unsafe extern "C" fn exception_create_message(
+        scope: *mut HandleScopeOpaque,
+        exception: LocalValueOpaque,
+    ) -> LocalMessageOpaque {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        v8::Exception::create_message(scope, exception.into()).into()
+    }
+
+    pub unsafe extern "C" fn exception_get_stack_trace(
+        scope: *mut HandleScopeOpaque,
+        exception: LocalValueOpaque,
+        ret: *mut LocalStackTraceOpaque,
+    ) -> bool {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        match v8::Exception::get_stack_trace(scope, exception.into()) {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub mod message {
+        use super::*;
+
+        pub unsafe extern "C" fn get(
+            ptr: LocalMessageOpaque,
+            scope: *mut HandleScopeOpaque,
+        ) -> LocalStringOpaque {
+            let message: v8::Local<'static, v8::Message> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            message.get(scope).into()
+        }
+
+        pub unsafe extern "C" fn get_source_line(
+            ptr: LocalMessageOpaque,
+            scope: *mut HandleScopeOpaque,
+            ret: *mut LocalStringOpaque,
+        ) -> bool {
+            let message: v8::Local<'static, v8::Message> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            match message.get_source_line(scope) {
+                Some(v) => {
+                    *ret = v.into();
+                    true
+                }
+                None => false,
+            }
+        }
+
+        pub unsafe extern "C" fn get_script_resource_name(
+            ptr: LocalMessageOpaque,
+            scope: *mut HandleScopeOpaque,
+            ret: *mut LocalValueOpaque,
+        ) -> bool {
+            let message: v8::Local<'static, v8::Message> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            match message.get_script_resource_name(scope) {
+                Some(v) => {
+                    *ret = v.into();
+                    true
+                }
+                None => false,
+            }
+        }
+
+        pub unsafe extern "C" fn get_line_number(
+            ptr: LocalMessageOpaque,
+            scope: *mut HandleScopeOpaque,
+            ret: *mut usize,
+        ) -> bool {
+            let message: v8::Local<'static, v8::Message> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            match message.get_line_number(scope) {
+                Some(v) => {
+                    *ret = v;
+                    true
+                }
+                None => false,
+            }
+        }
+
+        pub unsafe extern "C" fn get_start_position(ptr: LocalMessageOpaque) -> c_int {
+            let message: v8::Local<'static, v8::Message> = ptr.into();
+            message.get_start_position()
+        }
+
+        pub unsafe extern "C" fn get_end_position(ptr: LocalMessageOpaque) -> c_int {
+            let message: v8::Local<'static, v8::Message> = ptr.into();
+            message.get_end_position()
+        }
+
+        pub unsafe extern "C" fn get_start_column(ptr: LocalMessageOpaque) -> usize {
+            let message: v8::Local<'static, v8::Message> = ptr.into();
+            message.get_start_column()
+        }
+
+        pub unsafe extern "C" fn get_end_column(ptr: LocalMessageOpaque) -> usize {
+            let message: v8::Local<'static, v8::Message> = ptr.into();
+            message.get_end_column()
+        }
+
+        pub unsafe extern "C" fn error_level(ptr: LocalMessageOpaque) -> c_int {
+            let message: v8::Local<'static, v8::Message> = ptr.into();
+            message.error_level()
+        }
+
+        /// Only available if stack traces are captured for uncaught exceptions
+        pub unsafe extern "C" fn get_stack_trace(
+            ptr: LocalMessageOpaque,
+            scope: *mut HandleScopeOpaque,
+            ret: *mut LocalStackTraceOpaque,
+        ) -> bool {
+            let message: v8::Local<'static, v8::Message> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            match message.get_stack_trace(scope) {
+                Some(v) => {
+                    *ret = v.into();
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    pub mod stack_trace {
+        use super::*;
+
+        pub unsafe extern "C" fn current_stack_trace(
+            scope: *mut HandleScopeOpaque,
+            frame_limit: usize,
+            ret: *mut LocalStackTraceOpaque,
+        ) -> bool {
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            match v8::StackTrace::current_stack_trace(scope, frame_limit) {
+                Some(v) => {
+                    *ret = v.into();
+                    true
+                }
+                None => false,
+            }
+        }
+
+        /// Cheaper than capturing a stack trace when only the calling script is needed
+        pub unsafe extern "C" fn current_script_name_or_source_url(
+            scope: *mut HandleScopeOpaque,
+            ret: *mut LocalStringOpaque,
+        ) -> bool {
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            match v8::StackTrace::current_script_name_or_source_url(scope) {
+                Some(v) => {
+                    *ret = v.into();
+                    true
+                }
+                None => false,
+            }
+        }
+
+        pub unsafe extern "C" fn get_frame_count(ptr: LocalStackTraceOpaque) -> usize {
+            let stack_trace: v8::Local<'static, v8::StackTrace> = ptr.into();
+            stack_trace.get_frame_count()
+        }
+
+        pub unsafe extern "C" fn get_frame(
+            ptr: LocalStackTraceOpaque,
+            scope: *mut HandleScopeOpaque,
+            index: usize,
+            ret: *mut StackFrame,
+        ) -> bool {
+            let stack_trace: v8::Local<'static, v8::StackTrace> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            match stack_trace.get_frame(scope, index) {
+                Some(v) => {
+                    *ret = StackFrame::new(scope, v);
+                    true
+                }
+                None => false,
+            }
+        }
+
+        /// Fills up to `capacity` frames from the top of the stack, returns the number written
+        pub unsafe extern "C" fn get_frames(
+            ptr: LocalStackTraceOpaque,
+            scope: *mut HandleScopeOpaque,
+            frames: *mut StackFrame,
+            capacity: usize,
+        ) -> usize {
+            let stack_trace: v8::Local<'static, v8::StackTrace> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            let count = stack_trace.get_frame_count().min(capacity);
+            for i in 0..count {
+                match stack_trace.get_frame(scope, i) {
+                    Some(v) => frames.add(i).write(StackFrame::new(scope, v)),
+                    None => return i,
+                }
+            }
+            count
+        }
+    }
+}
+
+#[test]
+fn current_stack_trace_from_host_function() {
+    type Frame = (String, Option<String>, usize, bool);
+
+    unsafe extern "C" fn probe(
+        scope: *mut HandleScopeOpaque,
+        _info: *mut crate::FunctionCallbackInfoOpaque,
+        data: *mut c_void,
+    ) {
+        let out = &mut *(data as *mut Vec<Frame>);
+        let mut trace = std::mem::MaybeUninit::<LocalStackTraceOpaque>::uninit();
+        assert!(impls::stack_trace::current_stack_trace(
+            scope,
+            10,
+            trace.as_mut_ptr()
+        ));
+        let mut frames = Vec::<StackFrame>::with_capacity(10);
+        let count =
+            impls::stack_trace::get_frames(trace.assume_init(), scope, frames.as_mut_ptr(), 10);
+        frames.set_len(count);
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        for frame in frames {
+            let string = |s: LocalStringOpaque| {
+                std::mem::transmute::<LocalStringOpaque, Option<v8::Local<v8::String>>>(s)
+            };
+            let function_name = string(frame.function_name).unwrap();
+            out.push((
+                function_name.to_rust_string_lossy(scope),
+                string(frame.script_name).map(|s| s.to_rust_string_lossy(scope)),
+                frame.line_number,
+                frame.is_user_javascript,
+            ));
+        }
+    }
+
+    let mut frames = Vec::<Frame>::new();
+    crate::with_test_context(|scope| {
+        let data = &mut frames as *mut Vec<Frame> as *mut c_void;
+        let function = crate::new_host_function(scope, probe, data).unwrap();
+        let global = scope.get_current_context().global(scope);
+        let key = v8::String::new(scope, "probe").unwrap();
+        global.set(scope, key.into(), function.into());
+        let source = "function inner() { probe() }\nfunction outer() { inner() }\nouter()";
+        let source = v8::String::new(scope, source).unwrap();
+        let script = v8::Script::compile(scope, source, None).unwrap();
+        script.run(scope).unwrap();
+    });
+
+    // the host function itself is not a frame, and the script has no name
+    assert_eq!(frames.len(), 3);
+    assert_eq!(frames[0], ("inner".to_string(), None, 1, true));
+    assert_eq!(frames[1], ("outer".to_string(), None, 2, true));
+    assert_eq!((frames[2].1.as_deref(), frames[2].2), (None, 3));
+}
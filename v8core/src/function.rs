@@ -0,0 +1,215 @@
+use crate::{impl_transmute_cast, HandleScopeOpaque, LocalObjectOpaque, LocalValueOpaque};
+use std::ffi::{c_int, c_void};
+use v8::MapFnTo;
+
+#[repr(C)]
+pub struct LocalFunctionOpaque(*mut c_void);
+
+impl_transmute_cast! { LocalFunctionOpaque as v8::Local<'static, v8::Function> }
+
+#[repr(C)]
+pub struct FunctionCallbackInfoOpaque;
+
+#[repr(C)]
+pub struct FunctionVTable {
+    ctor: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        callback: unsafe extern "C" fn(
+            scope: *mut HandleScopeOpaque,
+            info: *mut FunctionCallbackInfoOpaque,
+            data: *mut c_void,
+        ),
+        data: *mut c_void,
+        ret: *mut LocalFunctionOpaque,
+    ) -> bool,
+    as_value: unsafe extern "C" fn(ptr: LocalFunctionOpaque) -> LocalValueOpaque,
+    from_value:
+        unsafe extern "C" fn(value: LocalValueOpaque, ret: *mut LocalFunctionOpaque) -> bool,
+    call: unsafe extern "C" fn(
+        ptr: LocalFunctionOpaque,
+        scope: *mut HandleScopeOpaque,
+        recv: LocalValueOpaque,
+        args: *const LocalValueOpaque,
+        args_len: usize,
+        ret: *mut LocalValueOpaque,
+    ) -> bool,
+
+    // callback info
+    info_length: unsafe extern "C" fn(info: *mut FunctionCallbackInfoOpaque) -> c_int,
+    info_get:
+        unsafe extern "C" fn(info: *mut FunctionCallbackInfoOpaque, i: c_int) -> LocalValueOpaque,
+    info_this: unsafe extern "C" fn(info: *mut FunctionCallbackInfoOpaque) -> LocalObjectOpaque,
+    info_new_target:
+        unsafe extern "C" fn(info: *mut FunctionCallbackInfoOpaque) -> LocalValueOpaque,
+    info_set_return_value:
+        unsafe extern "C" fn(info: *mut FunctionCallbackInfoOpaque, value: LocalValueOpaque),
+}
+
+pub const FUNCTION_VTABLE: FunctionVTable = FunctionVTable {
+    ctor: impls::function_new,
+    as_value: impls::function_as_value,
+    from_value: impls::function_from_value,
+    call: impls::function_call,
+
+    // callback info
+    info_length: impls::info::length,
+    info_get: impls::info::get,
+    info_this: impls::info::this,
+    info_new_target: impls::info::new_target,
+    info_set_return_value: impls::info::set_return_value,
+};
+
+pub(crate) type HostFunctionCallback = unsafe extern "C" fn(
+    scope: *mut HandleScopeOpaque,
+    info: *mut FunctionCallbackInfoOpaque,
+    data: *mut c_void,
+);
+
+struct CallbackInfo<'s> {
+    args: v8::FunctionCallbackArguments<'s>,
+    rv: v8::ReturnValue<'s>,
+}
+
+/// Creates a function that forwards to a host callback, the callback and its data are stored in
+/// the function data as a pair of externals, so nothing has to be freed when the function is collected.
+pub(crate) fn new_host_function<'s>(
+    scope: &mut v8::HandleScope<'s>,
+    callback: HostFunctionCallback,
+    data: *mut c_void,
+) -> Option<v8::Local<'s, v8::Function>> {
+    let pair = [
+        v8::External::new(scope, callback as *mut c_void).into(),
+        v8::External::new(scope, data).into(),
+    ];
+    let pair = v8::Array::new_with_elements(scope, &pair);
+    v8::Function::builder_raw(host_function_callback())
+        .data(pair.into())
+        .build(scope)
+}
+
+/// The native callback behind every host function, snapshots need it as an external reference
+pub(crate) fn host_function_callback() -> v8::FunctionCallback {
+    host_function_trampoline.map_fn_to()
+}
+
+fn host_function_trampoline(
+    scope: &mut v8::HandleScope,
+    args: v8::FunctionCallbackArguments,
+    rv: v8::ReturnValue,
+) {
+    let pair: v8::Local<v8::Array> = args.data().try_into().unwrap();
+    let callback: v8::Local<v8::External> = pair.get_index(scope, 0).unwrap().try_into().unwrap();
+    let data: v8::Local<v8::External> = pair.get_index(scope, 1).unwrap().try_into().unwrap();
+    let callback: HostFunctionCallback = unsafe { std::mem::transmute(callback.value()) };
+    let mut info = CallbackInfo { args, rv };
+    unsafe {
+        callback(
+            scope as *mut _ as *mut HandleScopeOpaque,
+            &mut info as *mut _ as *mut FunctionCallbackInfoOpaque,
+            data.value(),
+        )
+    }
+}
+
+mod impls {
+    use super::*;
+
+    pub unsafe extern "C" fn function_new(
+        scope: *mut HandleScopeOpaque,
+        callback: HostFunctionCallback,
+        data: *mut c_void,
+        ret: *mut LocalFunctionOpaque,
+    ) -> bool {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        let r = new_host_function(scope, callback, data);
+        match r {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn function_as_value(ptr: LocalFunctionOpaque) -> LocalValueOpaque {
+        let func: v8::Local<'static, v8::Function> = ptr.into();
+        let val: v8::Local<'static, v8::Value> = func.into();
+        val.into()
+    }
+
+    pub unsafe extern "C" fn function_from_value(
+        value: LocalValueOpaque,
+        ret: *mut LocalFunctionOpaque,
+    ) -> bool {
+        let val: v8::Local<'static, v8::Value> = value.into();
+        match v8::Local::<v8::Function>::try_from(val) {
+            Ok(v) => {
+                *ret = v.into();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    pub unsafe extern "C" fn function_call(
+        ptr: LocalFunctionOpaque,
+        scope: *mut HandleScopeOpaque,
+        recv: LocalValueOpaque,
+        args: *const LocalValueOpaque,
+        args_len: usize,
+        ret: *mut LocalValueOpaque,
+    ) -> bool {
+        let func: v8::Local<'static, v8::Function> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        let args: &[v8::Local<'static, v8::Value>] = if args_len == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts(args as *const v8::Local<'static, v8::Value>, args_len)
+        };
+        let r = func.call(scope, recv.into(), args);
+        match r {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub mod info {
+        use super::*;
+
+        pub unsafe extern "C" fn length(info: *mut FunctionCallbackInfoOpaque) -> c_int {
+            let info = &*(info as *mut CallbackInfo<'static>);
+            info.args.length()
+        }
+
+        pub unsafe extern "C" fn get(
+            info: *mut FunctionCallbackInfoOpaque,
+            i: c_int,
+        ) -> LocalValueOpaque {
+            let info = &*(info as *mut CallbackInfo<'static>);
+            info.args.get(i).into()
+        }
+
+        pub unsafe extern "C" fn this(info: *mut FunctionCallbackInfoOpaque) -> LocalObjectOpaque {
+            let info = &*(info as *mut CallbackInfo<'static>);
+            info.args.this().into()
+        }
+
+        pub unsafe extern "C" fn new_target(
+            info: *mut FunctionCallbackInfoOpaque,
+        ) -> LocalValueOpaque {
+            let info = &*(info as *mut CallbackInfo<'static>);
+            info.args.new_target().into()
+        }
+
+        pub unsafe extern "C" fn set_return_value(
+            info: *mut FunctionCallbackInfoOpaque,
+            value: LocalValueOpaque,
+        ) {
+            let info = &mut *(info as *mut CallbackInfo<'static>);
+            info.rv.set(value.into())
+        }
+    }
+}
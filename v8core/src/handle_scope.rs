@@ -1,4 +1,4 @@
-use crate::{impl_transmute_cast, IsolateOpaque, OwnedIsolateOpaque};
+use crate::{impl_transmute_cast, IsolateOpaque, LocalValueOpaque, OwnedIsolateOpaque};
 use std::ops::DerefMut;
 
 #[repr(C)]
@@ -11,6 +11,10 @@ pub struct HandleScopeVTable {
     ctor_isolate: unsafe extern "C" fn(ptr: *mut OwnedIsolateOpaque) -> HandleScopeOpaque,
     deref_to_isolate: unsafe extern "C" fn(ptr: *mut HandleScopeOpaque) -> *mut IsolateOpaque,
     isolate: *const HandleScopeImplVTable,
+    throw_exception: unsafe extern "C" fn(
+        ptr: *mut HandleScopeOpaque,
+        exception: LocalValueOpaque,
+    ) -> LocalValueOpaque,
 }
 
 #[repr(C)]
@@ -28,6 +32,7 @@ pub const HANDLE_SCOPE_VTABLE: HandleScopeVTable = HandleScopeVTable {
     ctor_isolate: impls::handle_scope_isolate_new,
     deref_to_isolate: impls::handle_scope_isolate_deref_to_isolate,
     isolate: &HANDLE_SCOPE_ISOLATE_VTABLE,
+    throw_exception: impls::handle_scope_throw_exception,
 };
 
 pub const HANDLE_SCOPE_ISOLATE_VTABLE: HandleScopeImplVTable = HandleScopeImplVTable {
@@ -57,4 +62,13 @@ mod impls {
         let scope: &mut v8::Isolate = scope.deref_mut();
         scope as *mut _ as *mut IsolateOpaque
     }
+
+    /// Schedules the exception to be thrown when returning to JavaScript, always returns undefined
+    pub unsafe extern "C" fn handle_scope_throw_exception(
+        ptr: *mut HandleScopeOpaque,
+        exception: LocalValueOpaque,
+    ) -> LocalValueOpaque {
+        let scope = &mut *(ptr as *mut v8::HandleScope<'static>);
+        scope.throw_exception(exception.into()).into()
+    }
 }
@@ -1,5 +1,9 @@
-use crate::{impl_transmute_cast, OptionBool};
-use std::ffi::{c_char, c_int};
+use crate::{
+    external_references, impl_transmute_cast, ByteSlice, FunctionCallbackInfoOpaque,
+    HandleScopeOpaque, LocalFunctionOpaque, LocalMessageOpaque, LocalPromiseOpaque,
+    LocalValueOpaque, OptionBool, PromiseRejectEvent,
+};
+use std::ffi::{c_char, c_int, c_void};
 
 #[repr(C)]
 pub struct IsolateOpaque;
@@ -20,6 +24,116 @@ pub struct IsolateCreateParams {
     set_heap_limits: bool,
     heap_limits_initial: usize,
     heap_limits_max: usize,
+    snapshot_blob: *const u8,
+    snapshot_blob_len: usize,
+    external_references: *const *const c_void,
+    external_references_len: usize,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MicrotasksPolicy {
+    Explicit = 0,
+    Auto = 2,
+}
+
+impl From<v8::MicrotasksPolicy> for MicrotasksPolicy {
+    fn from(value: v8::MicrotasksPolicy) -> Self {
+        match value {
+            v8::MicrotasksPolicy::Explicit => Self::Explicit,
+            v8::MicrotasksPolicy::Auto => Self::Auto,
+        }
+    }
+}
+
+impl From<MicrotasksPolicy> for v8::MicrotasksPolicy {
+    fn from(value: MicrotasksPolicy) -> Self {
+        match value {
+            MicrotasksPolicy::Explicit => Self::Explicit,
+            MicrotasksPolicy::Auto => Self::Auto,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemoryPressureLevel {
+    None = 0,
+    Moderate = 1,
+    Critical = 2,
+}
+
+impl From<MemoryPressureLevel> for v8::MemoryPressureLevel {
+    fn from(value: MemoryPressureLevel) -> Self {
+        match value {
+            MemoryPressureLevel::None => Self::None,
+            MemoryPressureLevel::Moderate => Self::Moderate,
+            MemoryPressureLevel::Critical => Self::Critical,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GarbageCollectionType {
+    Full,
+    Minor,
+}
+
+impl From<GarbageCollectionType> for v8::GarbageCollectionType {
+    fn from(value: GarbageCollectionType) -> Self {
+        match value {
+            GarbageCollectionType::Full => Self::Full,
+            GarbageCollectionType::Minor => Self::Minor,
+        }
+    }
+}
+
+/// Same layout as `v8::OomDetails`
+#[repr(C)]
+pub struct OomDetails {
+    is_heap_oom: bool,
+    detail: *const c_char,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HeapStatistics {
+    total_heap_size: usize,
+    total_heap_size_executable: usize,
+    total_physical_size: usize,
+    total_available_size: usize,
+    total_global_handles_size: usize,
+    used_global_handles_size: usize,
+    used_heap_size: usize,
+    heap_size_limit: usize,
+    malloced_memory: usize,
+    external_memory: usize,
+    peak_malloced_memory: usize,
+    number_of_native_contexts: usize,
+    number_of_detached_contexts: usize,
+    does_zap_garbage: bool,
+}
+
+impl From<&v8::HeapStatistics> for HeapStatistics {
+    fn from(s: &v8::HeapStatistics) -> Self {
+        Self {
+            total_heap_size: s.total_heap_size(),
+            total_heap_size_executable: s.total_heap_size_executable(),
+            total_physical_size: s.total_physical_size(),
+            total_available_size: s.total_available_size(),
+            total_global_handles_size: s.total_global_handles_size(),
+            used_global_handles_size: s.used_global_handles_size(),
+            used_heap_size: s.used_heap_size(),
+            heap_size_limit: s.heap_size_limit(),
+            malloced_memory: s.malloced_memory(),
+            external_memory: s.external_memory(),
+            peak_malloced_memory: s.peak_malloced_memory(),
+            number_of_native_contexts: s.number_of_native_contexts(),
+            number_of_detached_contexts: s.number_of_detached_contexts(),
+            does_zap_garbage: s.does_zap_garbage() != 0,
+        }
+    }
 }
 
 #[repr(C)]
@@ -28,6 +142,93 @@ pub struct IsolateVTable {
     ctor: unsafe extern "C" fn(params: IsolateCreateParams) -> OwnedIsolateOpaque,
     ctor_default: unsafe extern "C" fn() -> OwnedIsolateOpaque,
     deref: unsafe extern "C" fn(ptr: *mut OwnedIsolateOpaque) -> *mut IsolateOpaque,
+
+    // microtasks
+    get_microtasks_policy: unsafe extern "C" fn(ptr: *mut IsolateOpaque) -> MicrotasksPolicy,
+    set_microtasks_policy: unsafe extern "C" fn(ptr: *mut IsolateOpaque, policy: MicrotasksPolicy),
+    perform_microtask_checkpoint: unsafe extern "C" fn(ptr: *mut IsolateOpaque),
+    enqueue_microtask:
+        unsafe extern "C" fn(ptr: *mut IsolateOpaque, microtask: LocalFunctionOpaque),
+    enqueue_microtask_callback: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        callback: unsafe extern "C" fn(
+            scope: *mut HandleScopeOpaque,
+            info: *mut FunctionCallbackInfoOpaque,
+            data: *mut c_void,
+        ),
+        data: *mut c_void,
+    ) -> bool,
+
+    // promise
+    set_promise_reject_callback: unsafe extern "C" fn(
+        ptr: *mut IsolateOpaque,
+        callback: Option<
+            unsafe extern "C" fn(
+                scope: *mut HandleScopeOpaque,
+                event: PromiseRejectEvent,
+                promise: LocalPromiseOpaque,
+                value: LocalValueOpaque,
+                data: *mut c_void,
+            ),
+        >,
+        data: *mut c_void,
+    ),
+
+    // message
+    set_message_listener: unsafe extern "C" fn(
+        ptr: *mut IsolateOpaque,
+        callback: unsafe extern "C" fn(
+            scope: *mut HandleScopeOpaque,
+            message: LocalMessageOpaque,
+            exception: LocalValueOpaque,
+            data: *mut c_void,
+        ),
+        data: *mut c_void,
+    ) -> bool,
+    remove_message_listener: unsafe extern "C" fn(ptr: *mut IsolateOpaque),
+    set_capture_stack_trace_for_uncaught_exceptions:
+        unsafe extern "C" fn(ptr: *mut IsolateOpaque, capture: bool, frame_limit: i32),
+
+    // heap limit
+    add_near_heap_limit_callback: unsafe extern "C" fn(
+        ptr: *mut IsolateOpaque,
+        callback: unsafe extern "C" fn(
+            data: *mut c_void,
+            current_heap_limit: usize,
+            initial_heap_limit: usize,
+        ) -> usize,
+        data: *mut c_void,
+    ),
+    remove_near_heap_limit_callback: unsafe extern "C" fn(
+        ptr: *mut IsolateOpaque,
+        callback: unsafe extern "C" fn(
+            data: *mut c_void,
+            current_heap_limit: usize,
+            initial_heap_limit: usize,
+        ) -> usize,
+        heap_limit: usize,
+    ),
+    set_oom_error_handler: unsafe extern "C" fn(
+        ptr: *mut IsolateOpaque,
+        callback: unsafe extern "C" fn(location: *const c_char, details: *const OomDetails),
+    ),
+
+    // heap
+    get_heap_statistics: unsafe extern "C" fn(ptr: *mut IsolateOpaque, ret: *mut HeapStatistics),
+    adjust_amount_of_external_allocated_memory:
+        unsafe extern "C" fn(ptr: *mut IsolateOpaque, change_in_bytes: i64) -> i64,
+    take_heap_snapshot: unsafe extern "C" fn(
+        ptr: *mut IsolateOpaque,
+        write: unsafe extern "C" fn(data: *mut c_void, chunk: ByteSlice) -> bool,
+        data: *mut c_void,
+    ),
+
+    // gc
+    low_memory_notification: unsafe extern "C" fn(ptr: *mut IsolateOpaque),
+    memory_pressure_notification:
+        unsafe extern "C" fn(ptr: *mut IsolateOpaque, level: MemoryPressureLevel),
+    request_garbage_collection_for_testing:
+        unsafe extern "C" fn(ptr: *mut IsolateOpaque, r#type: GarbageCollectionType) -> bool,
 }
 
 pub const ISOLATE_VTABLE: IsolateVTable = IsolateVTable {
@@ -35,6 +236,38 @@ pub const ISOLATE_VTABLE: IsolateVTable = IsolateVTable {
     ctor: impls::isolate_new,
     ctor_default: impls::isolate_new_default,
     deref: impls::isolate_deref,
+
+    // microtasks
+    get_microtasks_policy: impls::microtasks::get_microtasks_policy,
+    set_microtasks_policy: impls::microtasks::set_microtasks_policy,
+    perform_microtask_checkpoint: impls::microtasks::perform_microtask_checkpoint,
+    enqueue_microtask: impls::microtasks::enqueue_microtask,
+    enqueue_microtask_callback: impls::microtasks::enqueue_microtask_callback,
+
+    // promise
+    set_promise_reject_callback: impls::promise::set_promise_reject_callback,
+
+    // message
+    set_message_listener: impls::message::set_message_listener,
+    remove_message_listener: impls::message::remove_message_listener,
+    set_capture_stack_trace_for_uncaught_exceptions:
+        impls::message::set_capture_stack_trace_for_uncaught_exceptions,
+
+    // heap limit
+    add_near_heap_limit_callback: impls::heap_limit::add_near_heap_limit_callback,
+    remove_near_heap_limit_callback: impls::heap_limit::remove_near_heap_limit_callback,
+    set_oom_error_handler: impls::heap_limit::set_oom_error_handler,
+
+    // heap
+    get_heap_statistics: impls::heap::get_heap_statistics,
+    adjust_amount_of_external_allocated_memory:
+        impls::heap::adjust_amount_of_external_allocated_memory,
+    take_heap_snapshot: impls::heap::take_heap_snapshot,
+
+    // gc
+    low_memory_notification: impls::gc::low_memory_notification,
+    memory_pressure_notification: impls::gc::memory_pressure_notification,
+    request_garbage_collection_for_testing: impls::gc::request_garbage_collection_for_testing,
 };
 
 mod impls {
@@ -72,6 +305,17 @@ mod impls {
         if params.set_heap_limits {
             cp = cp.heap_limits(params.heap_limits_initial, params.heap_limits_max);
         }
+        if !params.snapshot_blob.is_null() {
+            // copied so the host does not have to keep the blob alive with the isolate
+            let blob = std::slice::from_raw_parts(params.snapshot_blob, params.snapshot_blob_len);
+            cp = cp.snapshot_blob(blob.to_vec());
+        }
+        if !params.snapshot_blob.is_null() || !params.external_references.is_null() {
+            cp = cp.external_references(external_references(
+                params.external_references,
+                params.external_references_len,
+            ));
+        }
         let iso = v8::Isolate::new(cp);
         iso.into()
     }
@@ -81,4 +325,336 @@ mod impls {
         let iso: &mut v8::Isolate = iso.deref_mut();
         iso as *mut _ as *mut IsolateOpaque
     }
+
+    pub mod microtasks {
+        use super::*;
+        use crate::{new_host_function, HostFunctionCallback};
+
+        pub unsafe extern "C" fn get_microtasks_policy(
+            ptr: *mut IsolateOpaque,
+        ) -> MicrotasksPolicy {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.get_microtasks_policy().into()
+        }
+
+        pub unsafe extern "C" fn set_microtasks_policy(
+            ptr: *mut IsolateOpaque,
+            policy: MicrotasksPolicy,
+        ) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.set_microtasks_policy(policy.into())
+        }
+
+        pub unsafe extern "C" fn perform_microtask_checkpoint(ptr: *mut IsolateOpaque) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.perform_microtask_checkpoint()
+        }
+
+        pub unsafe extern "C" fn enqueue_microtask(
+            ptr: *mut IsolateOpaque,
+            microtask: LocalFunctionOpaque,
+        ) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.enqueue_microtask(microtask.into())
+        }
+
+        pub unsafe extern "C" fn enqueue_microtask_callback(
+            scope: *mut HandleScopeOpaque,
+            callback: HostFunctionCallback,
+            data: *mut c_void,
+        ) -> bool {
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            match new_host_function(scope, callback, data) {
+                Some(microtask) => {
+                    scope.enqueue_microtask(microtask);
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+
+    pub mod promise {
+        use super::*;
+
+        type PromiseRejectCallback = unsafe extern "C" fn(
+            scope: *mut HandleScopeOpaque,
+            event: PromiseRejectEvent,
+            promise: LocalPromiseOpaque,
+            value: LocalValueOpaque,
+            data: *mut c_void,
+        );
+
+        struct PromiseRejectCallbackSlot {
+            callback: PromiseRejectCallback,
+            data: *mut c_void,
+        }
+
+        extern "C" fn promise_reject_callback(message: v8::PromiseRejectMessage) {
+            let message: v8::PromiseRejectMessage<'static> =
+                unsafe { std::mem::transmute(message) };
+            let scope = &mut unsafe { v8::CallbackScope::new(&message) };
+            let scope: &mut v8::HandleScope = scope;
+            let (callback, data) = match scope.get_slot::<PromiseRejectCallbackSlot>() {
+                Some(slot) => (slot.callback, slot.data),
+                None => return,
+            };
+            // v8 only provides a value for rejections, use undefined for the other events
+            let value = message
+                .get_value()
+                .unwrap_or_else(|| v8::undefined(scope).into());
+            unsafe {
+                callback(
+                    scope as *mut _ as *mut HandleScopeOpaque,
+                    message.get_event().into(),
+                    message.get_promise().into(),
+                    value.into(),
+                    data,
+                )
+            }
+        }
+
+        pub unsafe extern "C" fn set_promise_reject_callback(
+            ptr: *mut IsolateOpaque,
+            callback: Option<PromiseRejectCallback>,
+            data: *mut c_void,
+        ) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            match callback {
+                Some(callback) => {
+                    iso.set_slot(PromiseRejectCallbackSlot { callback, data });
+                    iso.set_promise_reject_callback(promise_reject_callback);
+                }
+                None => {
+                    iso.remove_slot::<PromiseRejectCallbackSlot>();
+                }
+            }
+        }
+    }
+
+    pub mod message {
+        use super::*;
+
+        type MessageCallback = unsafe extern "C" fn(
+            scope: *mut HandleScopeOpaque,
+            message: LocalMessageOpaque,
+            exception: LocalValueOpaque,
+            data: *mut c_void,
+        );
+
+        struct MessageListenerSlot {
+            callback: MessageCallback,
+            data: *mut c_void,
+        }
+
+        /// Kept after `remove_message_listener`, the trampoline stays registered with v8
+        struct MessageListenerRegistered;
+
+        extern "C" fn message_listener(
+            message: v8::Local<v8::Message>,
+            exception: v8::Local<v8::Value>,
+        ) {
+            let message: v8::Local<'static, v8::Message> = unsafe { std::mem::transmute(message) };
+            let exception: v8::Local<'static, v8::Value> =
+                unsafe { std::mem::transmute(exception) };
+            let scope = &mut unsafe { v8::CallbackScope::new(message) };
+            let scope: &mut v8::HandleScope = scope;
+            let (callback, data) = match scope.get_slot::<MessageListenerSlot>() {
+                Some(slot) => (slot.callback, slot.data),
+                None => return,
+            };
+            unsafe {
+                callback(
+                    scope as *mut _ as *mut HandleScopeOpaque,
+                    message.into(),
+                    exception.into(),
+                    data,
+                )
+            }
+        }
+
+        /// There is a single listener per isolate, a second call replaces the previous one.
+        /// rusty_v8 registers listeners for errors only, so warnings and logs never arrive.
+        pub unsafe extern "C" fn set_message_listener(
+            ptr: *mut IsolateOpaque,
+            callback: MessageCallback,
+            data: *mut c_void,
+        ) -> bool {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.set_slot(MessageListenerSlot { callback, data });
+            // v8 does not deduplicate listeners, the trampoline is only added once per isolate
+            if iso.get_slot::<MessageListenerRegistered>().is_some() {
+                return true;
+            }
+            let registered = iso.add_message_listener(message_listener);
+            if registered {
+                iso.set_slot(MessageListenerRegistered);
+            }
+            registered
+        }
+
+        /// rusty_v8 cannot unregister the listener, it stays registered but ignores messages
+        pub unsafe extern "C" fn remove_message_listener(ptr: *mut IsolateOpaque) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.remove_slot::<MessageListenerSlot>();
+        }
+
+        /// Makes messages of uncaught exceptions carry a stack trace of up to `frame_limit` frames
+        pub unsafe extern "C" fn set_capture_stack_trace_for_uncaught_exceptions(
+            ptr: *mut IsolateOpaque,
+            capture: bool,
+            frame_limit: i32,
+        ) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.set_capture_stack_trace_for_uncaught_exceptions(capture, frame_limit)
+        }
+    }
+
+    pub mod heap_limit {
+        use super::*;
+
+        type NearHeapLimitCallback = unsafe extern "C" fn(
+            data: *mut c_void,
+            current_heap_limit: usize,
+            initial_heap_limit: usize,
+        ) -> usize;
+
+        type OomErrorCallback =
+            unsafe extern "C" fn(location: *const c_char, details: *const OomDetails);
+
+        // the host callbacks have the same abi as the v8 ones, so they are passed through directly
+
+        pub unsafe extern "C" fn add_near_heap_limit_callback(
+            ptr: *mut IsolateOpaque,
+            callback: NearHeapLimitCallback,
+            data: *mut c_void,
+        ) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            let callback: v8::NearHeapLimitCallback = std::mem::transmute(callback);
+            iso.add_near_heap_limit_callback(callback, data)
+        }
+
+        pub unsafe extern "C" fn remove_near_heap_limit_callback(
+            ptr: *mut IsolateOpaque,
+            callback: NearHeapLimitCallback,
+            heap_limit: usize,
+        ) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            let callback: v8::NearHeapLimitCallback = std::mem::transmute(callback);
+            iso.remove_near_heap_limit_callback(callback, heap_limit)
+        }
+
+        pub unsafe extern "C" fn set_oom_error_handler(
+            ptr: *mut IsolateOpaque,
+            callback: OomErrorCallback,
+        ) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            let callback: v8::OomErrorCallback = std::mem::transmute(callback);
+            iso.set_oom_error_handler(callback)
+        }
+    }
+
+    pub mod heap {
+        use super::*;
+
+        pub unsafe extern "C" fn get_heap_statistics(
+            ptr: *mut IsolateOpaque,
+            ret: *mut HeapStatistics,
+        ) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            let mut s = v8::HeapStatistics::default();
+            iso.get_heap_statistics(&mut s);
+            *ret = (&s).into();
+        }
+
+        pub unsafe extern "C" fn adjust_amount_of_external_allocated_memory(
+            ptr: *mut IsolateOpaque,
+            change_in_bytes: i64,
+        ) -> i64 {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.adjust_amount_of_external_allocated_memory(change_in_bytes)
+        }
+
+        /// The snapshot JSON is streamed in chunks to `write`, returning false from it stops the stream
+        pub unsafe extern "C" fn take_heap_snapshot(
+            ptr: *mut IsolateOpaque,
+            write: unsafe extern "C" fn(data: *mut c_void, chunk: ByteSlice) -> bool,
+            data: *mut c_void,
+        ) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.take_heap_snapshot(|chunk| write(data, ByteSlice::new(chunk)))
+        }
+    }
+
+    pub mod gc {
+        use super::*;
+
+        pub unsafe extern "C" fn low_memory_notification(ptr: *mut IsolateOpaque) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.low_memory_notification()
+        }
+
+        pub unsafe extern "C" fn memory_pressure_notification(
+            ptr: *mut IsolateOpaque,
+            level: MemoryPressureLevel,
+        ) {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.memory_pressure_notification(level.into())
+        }
+
+        /// Only available with the `gc-testing` feature, returns false otherwise
+        #[cfg(feature = "gc-testing")]
+        pub unsafe extern "C" fn request_garbage_collection_for_testing(
+            ptr: *mut IsolateOpaque,
+            r#type: GarbageCollectionType,
+        ) -> bool {
+            let iso = &mut *(ptr as *mut v8::Isolate);
+            iso.request_garbage_collection_for_testing(r#type.into());
+            true
+        }
+
+        #[cfg(not(feature = "gc-testing"))]
+        pub unsafe extern "C" fn request_garbage_collection_for_testing(
+            _ptr: *mut IsolateOpaque,
+            _type: GarbageCollectionType,
+        ) -> bool {
+            false
+        }
+    }
+}
+
+#[test]
+fn message_listener_is_registered_once() {
+    unsafe extern "C" fn count(
+        _scope: *mut HandleScopeOpaque,
+        _message: LocalMessageOpaque,
+        _exception: LocalValueOpaque,
+        data: *mut c_void,
+    ) {
+        *(data as *mut usize) += 1;
+    }
+
+    fn throw(scope: &mut v8::HandleScope<'static>) {
+        // a verbose TryCatch reports the exception to the message listeners
+        let tc = &mut v8::TryCatch::new(scope);
+        tc.set_verbose(true);
+        let source = v8::String::new(tc, "throw 1").unwrap();
+        let script = v8::Script::compile(tc, source, None).unwrap();
+        assert!(script.run(tc).is_none());
+    }
+
+    let mut calls = 0usize;
+    crate::with_test_context(|scope| unsafe {
+        let iso: &mut v8::Isolate = scope;
+        let iso = iso as *mut v8::Isolate as *mut IsolateOpaque;
+        let data = &mut calls as *mut usize as *mut c_void;
+
+        assert!(impls::message::set_message_listener(iso, count, data));
+        throw(scope);
+        impls::message::remove_message_listener(iso);
+        throw(scope);
+        assert!(impls::message::set_message_listener(iso, count, data));
+        throw(scope);
+    });
+    assert_eq!(calls, 2);
 }
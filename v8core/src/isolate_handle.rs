@@ -0,0 +1,103 @@
+use crate::{impl_transmute_cast, IsolateOpaque, OwnedIsolateOpaque};
+use std::ffi::c_void;
+
+#[repr(C)]
+pub struct IsolateHandleOpaque(usize);
+
+impl_transmute_cast! { IsolateHandleOpaque as v8::IsolateHandle }
+
+/// All functions except `ctor` can be called from any thread
+#[repr(C)]
+pub struct IsolateHandleVTable {
+    drop: unsafe extern "C" fn(ptr: IsolateHandleOpaque),
+    clone: unsafe extern "C" fn(ptr: *const IsolateHandleOpaque) -> IsolateHandleOpaque,
+    ctor: unsafe extern "C" fn(isolate: *mut OwnedIsolateOpaque) -> IsolateHandleOpaque,
+    terminate_execution: unsafe extern "C" fn(ptr: *const IsolateHandleOpaque) -> bool,
+    cancel_terminate_execution: unsafe extern "C" fn(ptr: *const IsolateHandleOpaque) -> bool,
+    is_execution_terminating: unsafe extern "C" fn(ptr: *const IsolateHandleOpaque) -> bool,
+    request_interrupt: unsafe extern "C" fn(
+        ptr: *const IsolateHandleOpaque,
+        callback: unsafe extern "C" fn(isolate: *mut IsolateOpaque, data: *mut c_void),
+        data: *mut c_void,
+    ) -> bool,
+}
+
+pub const ISOLATE_HANDLE_VTABLE: IsolateHandleVTable = IsolateHandleVTable {
+    drop: impls::isolate_handle_drop,
+    clone: impls::isolate_handle_clone,
+    ctor: impls::isolate_handle_new,
+    terminate_execution: impls::isolate_handle_terminate_execution,
+    cancel_terminate_execution: impls::isolate_handle_cancel_terminate_execution,
+    is_execution_terminating: impls::isolate_handle_is_execution_terminating,
+    request_interrupt: impls::isolate_handle_request_interrupt,
+};
+
+mod impls {
+    use super::*;
+
+    pub unsafe extern "C" fn isolate_handle_drop(ptr: IsolateHandleOpaque) {
+        let handle: v8::IsolateHandle = ptr.into();
+        drop(handle)
+    }
+
+    pub unsafe extern "C" fn isolate_handle_clone(
+        ptr: *const IsolateHandleOpaque,
+    ) -> IsolateHandleOpaque {
+        let handle = &*(ptr as *const v8::IsolateHandle);
+        handle.clone().into()
+    }
+
+    pub unsafe extern "C" fn isolate_handle_new(
+        isolate: *mut OwnedIsolateOpaque,
+    ) -> IsolateHandleOpaque {
+        let isolate = &*(isolate as *mut v8::OwnedIsolate);
+        isolate.thread_safe_handle().into()
+    }
+
+    pub unsafe extern "C" fn isolate_handle_terminate_execution(
+        ptr: *const IsolateHandleOpaque,
+    ) -> bool {
+        let handle = &*(ptr as *const v8::IsolateHandle);
+        handle.terminate_execution()
+    }
+
+    pub unsafe extern "C" fn isolate_handle_cancel_terminate_execution(
+        ptr: *const IsolateHandleOpaque,
+    ) -> bool {
+        let handle = &*(ptr as *const v8::IsolateHandle);
+        handle.cancel_terminate_execution()
+    }
+
+    pub unsafe extern "C" fn isolate_handle_is_execution_terminating(
+        ptr: *const IsolateHandleOpaque,
+    ) -> bool {
+        let handle = &*(ptr as *const v8::IsolateHandle);
+        handle.is_execution_terminating()
+    }
+
+    struct Interrupt {
+        callback: unsafe extern "C" fn(isolate: *mut IsolateOpaque, data: *mut c_void),
+        data: *mut c_void,
+    }
+
+    extern "C" fn interrupt_callback(isolate: &mut v8::Isolate, data: *mut c_void) {
+        let interrupt = unsafe { Box::from_raw(data as *mut Interrupt) };
+        unsafe {
+            (interrupt.callback)(isolate as *mut _ as *mut IsolateOpaque, interrupt.data);
+        }
+    }
+
+    pub unsafe extern "C" fn isolate_handle_request_interrupt(
+        ptr: *const IsolateHandleOpaque,
+        callback: unsafe extern "C" fn(isolate: *mut IsolateOpaque, data: *mut c_void),
+        data: *mut c_void,
+    ) -> bool {
+        let handle = &*(ptr as *const v8::IsolateHandle);
+        let interrupt = Box::into_raw(Box::new(Interrupt { callback, data }));
+        let r = handle.request_interrupt(interrupt_callback, interrupt as *mut c_void);
+        if !r {
+            drop(Box::from_raw(interrupt));
+        }
+        r
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{HandleScopeOpaque, LocalStringOpaque, LocalValueOpaque};
+
+#[repr(C)]
+pub struct JsonVTable {
+    parse: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        json: LocalStringOpaque,
+        ret: *mut LocalValueOpaque,
+    ) -> bool,
+    stringify: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        value: LocalValueOpaque,
+        gap: *const LocalValueOpaque,
+        ret: *mut LocalStringOpaque,
+    ) -> bool,
+}
+
+pub const JSON_VTABLE: JsonVTable = JsonVTable {
+    parse: impls::parse,
+    stringify: impls::stringify,
+};
+
+/// The gap of `JSON.stringify`: up to 10 spaces for a number, the first 10 code units of a string
+fn gap_of(scope: &mut v8::HandleScope<'static>, gap: v8::Local<'static, v8::Value>) -> String {
+    if gap.is_number() {
+        let n = gap.number_value(scope).unwrap_or(0.0);
+        " ".repeat(n.clamp(0.0, 10.0) as usize)
+    } else if let Ok(gap) = v8::Local::<v8::String>::try_from(gap) {
+        let mut buf = [0u16; 10];
+        let len = gap.write(scope, &mut buf, 0, v8::WriteOptions::NO_NULL_TERMINATION);
+        String::from_utf16_lossy(&buf[..len])
+    } else {
+        String::new()
+    }
+}
+
+/// Indents compact JSON the way `JSON.stringify` does when given `gap`
+pub(crate) fn indent_json(json: &str, gap: &str) -> String {
+    if gap.is_empty() {
+        return json.to_owned();
+    }
+    let mut out = String::with_capacity(json.len() * 2);
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = json.chars().peekable();
+    let newline = |out: &mut String, depth: usize| {
+        out.push('\n');
+        for _ in 0..depth {
+            out.push_str(gap);
+        }
+    };
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            }
+            '{' | '[' => {
+                out.push(c);
+                // empty objects and arrays stay on one line
+                if matches!(chars.peek(), Some('}' | ']')) {
+                    out.push(chars.next().unwrap());
+                } else {
+                    depth += 1;
+                    newline(&mut out, depth);
+                }
+            }
+            '}' | ']' => {
+                depth = depth.saturating_sub(1);
+                newline(&mut out, depth);
+                out.push(c);
+            }
+            ',' => {
+                out.push(c);
+                newline(&mut out, depth);
+            }
+            ':' => out.push_str(": "),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+mod impls {
+    use super::*;
+
+    /// On failure the SyntaxError is left pending on the scope for the host to catch
+    pub unsafe extern "C" fn parse(
+        scope: *mut HandleScopeOpaque,
+        json: LocalStringOpaque,
+        ret: *mut LocalValueOpaque,
+    ) -> bool {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        match v8::json::parse(scope, json.into()) {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// `gap` can be null, otherwise it is applied like the third argument of `JSON.stringify`.
+    /// v8 serializes natively in both cases, so scripts replacing `JSON.stringify` have no effect.
+    /// Exceptions, e.g. a TypeError for cyclic values, are left pending on the scope.
+    pub unsafe extern "C" fn stringify(
+        scope: *mut HandleScopeOpaque,
+        value: LocalValueOpaque,
+        gap: *const LocalValueOpaque,
+        ret: *mut LocalStringOpaque,
+    ) -> bool {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        let r = (|| {
+            let json = v8::json::stringify(scope, value.into())?;
+            if gap.is_null() {
+                return Some(json);
+            }
+            let gap = gap_of(scope, gap.read().into());
+            if gap.is_empty() {
+                return Some(json);
+            }
+            let json = indent_json(&json.to_rust_string_lossy(scope), &gap);
+            v8::String::new(scope, &json)
+        })();
+        match r {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[test]
+fn parse_and_stringify() {
+    fn eval(scope: &mut v8::HandleScope<'static>, source: &str) -> v8::Local<'static, v8::Value> {
+        let source = v8::String::new(scope, source).unwrap();
+        let script = v8::Script::compile(scope, source, None).unwrap();
+        script.run(scope).unwrap()
+    }
+
+    unsafe extern "C" fn parse_invalid(
+        scope: *mut HandleScopeOpaque,
+        _info: *mut crate::FunctionCallbackInfoOpaque,
+        _data: *mut std::ffi::c_void,
+    ) {
+        let json = v8::String::new(&mut *(scope as *mut v8::HandleScope<'static>), "{").unwrap();
+        let mut ret = std::mem::MaybeUninit::<LocalValueOpaque>::uninit();
+        assert!(!impls::parse(scope, json.into(), ret.as_mut_ptr()));
+    }
+
+    crate::with_test_context(|scope| unsafe {
+        let s = scope as *mut v8::HandleScope<'static> as *mut HandleScopeOpaque;
+        let json = v8::String::new(scope, r#"{"a":[1,{}],"b":"x,{\":y","c":[]}"#).unwrap();
+        let mut value = std::mem::MaybeUninit::<LocalValueOpaque>::uninit();
+        assert!(impls::parse(s, json.into(), value.as_mut_ptr()));
+        let value: v8::Local<v8::Value> = value.assume_init().into();
+        let global = scope.get_current_context().global(scope);
+        let key = v8::String::new(scope, "value").unwrap();
+        global.set(scope, key.into(), value);
+
+        // the gap output matches the builtin, and replacing the builtin does not affect it
+        eval(
+            scope,
+            "const stringify = JSON.stringify; JSON.stringify = () => 'hijacked'",
+        );
+        for gap in ["2", "'--'", "20", "null"] {
+            let expected = eval(scope, &format!("stringify(value, null, {gap})"));
+            let gap = eval(scope, gap);
+            let mut ret = std::mem::MaybeUninit::<LocalStringOpaque>::uninit();
+            assert!(impls::stringify(
+                s,
+                value.into(),
+                &gap.into(),
+                ret.as_mut_ptr()
+            ));
+            let ret: v8::Local<v8::String> = ret.assume_init().into();
+            assert!(expected.strict_equals(ret.into()));
+        }
+
+        // a parse error in a host function reaches the calling script
+        let function = crate::new_host_function(scope, parse_invalid, std::ptr::null_mut());
+        let key = v8::String::new(scope, "parseInvalid").unwrap();
+        global.set(scope, key.into(), function.unwrap().into());
+        let caught = eval(
+            scope,
+            "try { parseInvalid(); false } catch (e) { e instanceof SyntaxError }",
+        );
+        assert!(caught.is_true());
+    });
+}
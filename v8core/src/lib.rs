@@ -1,22 +1,48 @@
 pub mod context;
 pub mod context_scope;
+pub mod date;
+pub mod exception;
+pub mod function;
 pub mod handle_scope;
 pub mod isolate;
+pub mod isolate_handle;
+pub mod json;
+pub mod object;
 pub mod platform;
+pub mod promise;
+pub mod regexp;
 pub mod script;
+pub mod script_compiler;
 pub mod shared_ptr;
+pub mod snapshot;
 pub mod string;
+pub mod symbol;
 pub mod value;
+pub mod value_deserializer;
+pub mod value_serializer;
 pub use context::*;
 pub use context_scope::*;
+pub use date::*;
+pub use exception::*;
+pub use function::*;
 pub use handle_scope::*;
 pub use isolate::*;
+pub use isolate_handle::*;
+pub use json::*;
+pub use object::*;
 pub use platform::*;
+pub use promise::*;
+pub use regexp::*;
 pub use script::*;
+pub use script_compiler::*;
 pub use shared_ptr::*;
+pub use snapshot::*;
 use std::ops::{Deref, DerefMut};
 pub use string::*;
+pub use symbol::*;
 pub use value::*;
+pub use value_deserializer::*;
+pub use value_serializer::*;
 
 #[macro_export]
 macro_rules! impl_transmute_cast {
@@ -57,16 +83,36 @@ pub struct RootVTable {
     script: *const ScriptVTable,
     value: *const ValueVTable,
     string: *const StringVTable,
+    symbol: *const SymbolVTable,
+    private: *const PrivateVTable,
+    object: *const ObjectVTable,
+    function: *const FunctionVTable,
+    promise: *const PromiseVTable,
+    promise_resolver: *const PromiseResolverVTable,
+    isolate_handle: *const IsolateHandleVTable,
+    snapshot_creator: *const SnapshotCreatorVTable,
+    script_compiler: *const ScriptCompilerVTable,
+    unbound_script: *const UnboundScriptVTable,
+    json: *const JsonVTable,
+    value_serializer: *const ValueSerializerVTable,
+    value_deserializer: *const ValueDeserializerVTable,
+    date: *const DateVTable,
+    reg_exp: *const RegExpVTable,
+    exception: *const ExceptionVTable,
+    message: *const MessageVTable,
+    stack_trace: *const StackTraceVTable,
 }
 
 #[repr(C)]
 pub struct V8VTable {
     initialize_platform: unsafe extern "C" fn(ptr: PlatformOpaque),
     initialize: unsafe extern "C" fn(),
-    auto_ensures_init: unsafe extern "C" fn(),
+    auto_ensures_init: unsafe extern "C" fn(flags: *const ByteSlice),
     is_initialized: unsafe extern "C" fn() -> bool,
     current_platform: unsafe extern "C" fn() -> PlatformOpaque,
     version: unsafe extern "C" fn() -> ByteSlice,
+    set_flags_from_string: unsafe extern "C" fn(flags: *const ByteSlice),
+    set_flags_from_list: unsafe extern "C" fn(flags: *const ByteSlice, len: usize) -> usize,
 }
 
 pub const V8_VTABLE: V8VTable = V8VTable {
@@ -76,6 +122,8 @@ pub const V8_VTABLE: V8VTable = V8VTable {
     is_initialized: impls::is_initialized,
     current_platform: impls::v8_get_current_platform,
     version: impls::v8_version,
+    set_flags_from_string: impls::v8_set_flags_from_string,
+    set_flags_from_list: impls::v8_set_flags_from_list,
 };
 
 pub const ROOT_VTABLE: RootVTable = RootVTable {
@@ -88,6 +136,24 @@ pub const ROOT_VTABLE: RootVTable = RootVTable {
     script: &SCRIPT_VTABLE,
     value: &VALUE_VTABLE,
     string: &STRING_VTABLE,
+    symbol: &SYMBOL_VTABLE,
+    private: &PRIVATE_VTABLE,
+    object: &OBJECT_VTABLE,
+    function: &FUNCTION_VTABLE,
+    promise: &PROMISE_VTABLE,
+    promise_resolver: &PROMISE_RESOLVER_VTABLE,
+    isolate_handle: &ISOLATE_HANDLE_VTABLE,
+    snapshot_creator: &SNAPSHOT_CREATOR_VTABLE,
+    script_compiler: &SCRIPT_COMPILER_VTABLE,
+    unbound_script: &UNBOUND_SCRIPT_VTABLE,
+    json: &JSON_VTABLE,
+    value_serializer: &VALUE_SERIALIZER_VTABLE,
+    value_deserializer: &VALUE_DESERIALIZER_VTABLE,
+    date: &DATE_VTABLE,
+    reg_exp: &REG_EXP_VTABLE,
+    exception: &EXCEPTION_VTABLE,
+    message: &MESSAGE_VTABLE,
+    stack_trace: &STACK_TRACE_VTABLE,
 };
 
 #[no_mangle]
@@ -108,18 +174,27 @@ mod impls {
 
     pub unsafe extern "C" fn v8_initialize() {
         INITED.store(true, std::sync::atomic::Ordering::Relaxed);
+        set_feature_flags();
         v8::V8::initialize();
     }
 
-    pub unsafe extern "C" fn auto_ensures_init() {
+    /// `flags` can be null, they are only applied if this call performs the initialization
+    pub unsafe extern "C" fn auto_ensures_init(flags: *const ByteSlice) {
         if INITED.swap(true, std::sync::atomic::Ordering::Relaxed) {
             return;
         }
+        v8_set_flags_from_string(flags);
         let plt = v8::new_default_platform(0, true).make_shared();
         v8::V8::initialize_platform(plt);
+        set_feature_flags();
         v8::V8::initialize();
     }
 
+    fn set_feature_flags() {
+        #[cfg(feature = "gc-testing")]
+        v8::V8::set_flags_from_string("--expose-gc");
+    }
+
     pub unsafe extern "C" fn is_initialized() -> bool {
         INITED.load(std::sync::atomic::Ordering::Relaxed)
     }
@@ -129,6 +204,32 @@ mod impls {
         ByteSlice::new(bytes)
     }
 
+    /// Must be called before `initialize`, `flags` can be null
+    pub unsafe extern "C" fn v8_set_flags_from_string(flags: *const ByteSlice) {
+        let Some(flags) = flags.as_ref() else {
+            return;
+        };
+        let flags = String::from_utf8_lossy(flags);
+        v8::V8::set_flags_from_string(&flags);
+    }
+
+    /// Must be called before `initialize`, returns the number of flags v8 did not recognize.
+    /// `flags` can be null when `len` is 0.
+    pub unsafe extern "C" fn v8_set_flags_from_list(flags: *const ByteSlice, len: usize) -> usize {
+        let flags = if len == 0 || flags.is_null() {
+            &[]
+        } else {
+            std::slice::from_raw_parts(flags, len)
+        };
+        let flags = flags
+            .iter()
+            .map(|f| String::from_utf8_lossy(f).into_owned());
+        // v8 expects the program name as the first argument
+        let args = std::iter::once("v8core".to_string()).chain(flags).collect();
+        let unrecognized = v8::V8::set_flags_from_command_line(args);
+        unrecognized.len().saturating_sub(1)
+    }
+
     pub unsafe extern "C" fn v8_get_current_platform() -> PlatformOpaque {
         v8::V8::get_current_platform().into()
     }
@@ -252,11 +353,41 @@ impl Default for OptionBool {
     }
 }
 
+#[cfg(test)]
+static TEST_UNRECOGNIZED_FLAGS: std::sync::OnceLock<usize> = std::sync::OnceLock::new();
+
+/// Initializes v8 once per test binary, the tests share the process and v8 only accepts flags
+/// before it is initialized
+#[cfg(test)]
+pub(crate) fn init_test_v8() {
+    TEST_UNRECOGNIZED_FLAGS.get_or_init(|| unsafe {
+        let flags = [
+            ByteSlice::new(b"--expose-gc"),
+            ByteSlice::new(b"--no-such-flag"),
+        ];
+        let unrecognized = impls::v8_set_flags_from_list(flags.as_ptr(), flags.len());
+        let platform = v8::new_default_platform(0, false).make_shared();
+        v8::V8::initialize_platform(platform);
+        v8::V8::initialize();
+        unrecognized
+    });
+}
+
+/// Runs `f` in a fresh isolate and context, the scope is cast to `'static` like the vtables do
+#[cfg(test)]
+pub(crate) fn with_test_context(f: impl FnOnce(&mut v8::HandleScope<'static>)) {
+    init_test_v8();
+    let isolate = &mut v8::Isolate::new(Default::default());
+    let scope = &mut v8::HandleScope::new(isolate);
+    let context = v8::Context::new(scope);
+    let scope = &mut v8::ContextScope::new(scope, context);
+    let scope = &mut **scope as *mut v8::HandleScope as *mut HandleScopeOpaque;
+    f(unsafe { &mut *(scope as *mut v8::HandleScope<'static>) })
+}
+
 #[test]
 fn test() {
-    let platform = v8::new_default_platform(0, false).make_shared();
-    v8::V8::initialize_platform(platform);
-    v8::V8::initialize();
+    init_test_v8();
     
     let isolate = &mut v8::Isolate::new(Default::default());
     
@@ -272,3 +403,21 @@ fn test() {
     let result = result.to_string(scope).unwrap();
     println!("result: {}", result.to_rust_string_lossy(scope));
 }
+
+#[test]
+fn set_flags_from_list() {
+    init_test_v8();
+    // null flags are no flags, this returns before reaching v8
+    unsafe { impls::v8_set_flags_from_string(std::ptr::null()) };
+    assert_eq!(TEST_UNRECOGNIZED_FLAGS.get(), Some(&1));
+
+    let isolate = &mut v8::Isolate::new(Default::default());
+    let scope = &mut v8::HandleScope::new(isolate);
+    let context = v8::Context::new(scope);
+    let scope = &mut v8::ContextScope::new(scope, context);
+
+    let code = v8::String::new(scope, "typeof gc").unwrap();
+    let script = v8::Script::compile(scope, code, None).unwrap();
+    let result = script.run(scope).unwrap();
+    assert_eq!(result.to_rust_string_lossy(scope), "function");
+}
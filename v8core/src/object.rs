@@ -0,0 +1,313 @@
+use crate::{
+    impl_transmute_cast, HandleScopeOpaque, LocalPrivateOpaque, LocalValueOpaque, OptionBool,
+};
+use std::ffi::c_void;
+
+#[repr(C)]
+pub struct LocalObjectOpaque(*mut c_void);
+
+impl_transmute_cast! { LocalObjectOpaque as v8::Local<'static, v8::Object> }
+
+#[repr(C)]
+pub struct ObjectVTable {
+    ctor: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalObjectOpaque,
+    as_value: unsafe extern "C" fn(ptr: LocalObjectOpaque) -> LocalValueOpaque,
+
+    // bulk
+    ctor_with_properties: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        prototype_or_null: LocalValueOpaque,
+        names: *const LocalValueOpaque,
+        values: *const LocalValueOpaque,
+        len: usize,
+        ret: *mut LocalObjectOpaque,
+    ) -> bool,
+    get_own_properties: unsafe extern "C" fn(
+        ptr: LocalObjectOpaque,
+        scope: *mut HandleScopeOpaque,
+        names: *mut LocalValueOpaque,
+        values: *mut LocalValueOpaque,
+        capacity: usize,
+        ret_len: *mut usize,
+    ) -> bool,
+
+    // private
+    get_private: unsafe extern "C" fn(
+        ptr: LocalObjectOpaque,
+        scope: *mut HandleScopeOpaque,
+        key: LocalPrivateOpaque,
+        ret: *mut LocalValueOpaque,
+    ) -> bool,
+    set_private: unsafe extern "C" fn(
+        ptr: LocalObjectOpaque,
+        scope: *mut HandleScopeOpaque,
+        key: LocalPrivateOpaque,
+        value: LocalValueOpaque,
+    ) -> OptionBool,
+    has_private: unsafe extern "C" fn(
+        ptr: LocalObjectOpaque,
+        scope: *mut HandleScopeOpaque,
+        key: LocalPrivateOpaque,
+    ) -> OptionBool,
+    delete_private: unsafe extern "C" fn(
+        ptr: LocalObjectOpaque,
+        scope: *mut HandleScopeOpaque,
+        key: LocalPrivateOpaque,
+    ) -> OptionBool,
+}
+
+pub const OBJECT_VTABLE: ObjectVTable = ObjectVTable {
+    ctor: impls::object_new,
+    as_value: impls::object_as_value,
+
+    // bulk
+    ctor_with_properties: impls::bulk::object_new_with_properties,
+    get_own_properties: impls::bulk::get_own_properties,
+
+    // private
+    get_private: impls::private::get_private,
+    set_private: impls::private::set_private,
+    has_private: impls::private::has_private,
+    delete_private: impls::private::delete_private,
+};
+
+mod impls {
+    use super::*;
+
+    pub unsafe extern "C" fn object_new(scope: *mut HandleScopeOpaque) -> LocalObjectOpaque {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        v8::Object::new(scope).into()
+    }
+
+    pub unsafe extern "C" fn object_as_value(ptr: LocalObjectOpaque) -> LocalValueOpaque {
+        let obj: v8::Local<'static, v8::Object> = ptr.into();
+        let val: v8::Local<'static, v8::Value> = obj.into();
+        val.into()
+    }
+
+    pub mod bulk {
+        use super::*;
+
+        /// Every name must be a string or a symbol and the prototype must be an object or null,
+        /// otherwise a TypeError is thrown
+        pub unsafe extern "C" fn object_new_with_properties(
+            scope: *mut HandleScopeOpaque,
+            prototype_or_null: LocalValueOpaque,
+            names: *const LocalValueOpaque,
+            values: *const LocalValueOpaque,
+            len: usize,
+            ret: *mut LocalObjectOpaque,
+        ) -> bool {
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            let (names, values): (
+                &[v8::Local<'static, v8::Value>],
+                &[v8::Local<'static, v8::Value>],
+            ) = if len == 0 {
+                (&[], &[])
+            } else {
+                (
+                    std::slice::from_raw_parts(names as *const v8::Local<'static, v8::Value>, len),
+                    std::slice::from_raw_parts(values as *const v8::Local<'static, v8::Value>, len),
+                )
+            };
+            if !names.iter().all(|name| name.is_name()) {
+                let message =
+                    v8::String::new(scope, "property names must be strings or symbols").unwrap();
+                let exception = v8::Exception::type_error(scope, message);
+                scope.throw_exception(exception);
+                return false;
+            }
+            let prototype: v8::Local<'static, v8::Value> = prototype_or_null.into();
+            if !(prototype.is_null() || prototype.is_object()) {
+                let message =
+                    v8::String::new(scope, "prototype must be an object or null").unwrap();
+                let exception = v8::Exception::type_error(scope, message);
+                scope.throw_exception(exception);
+                return false;
+            }
+            let names = std::slice::from_raw_parts(
+                names.as_ptr() as *const v8::Local<'static, v8::Name>,
+                len,
+            );
+            *ret =
+                v8::Object::with_prototype_and_properties(scope, prototype, names, values).into();
+            true
+        }
+
+        /// Collects the own enumerable string and symbol keyed properties. `ret_len` is always set
+        /// to the total count, when it exceeds `capacity` nothing is written and no getter runs, so
+        /// the host can retry with larger buffers without observable side effects.
+        pub unsafe extern "C" fn get_own_properties(
+            ptr: LocalObjectOpaque,
+            scope: *mut HandleScopeOpaque,
+            names: *mut LocalValueOpaque,
+            values: *mut LocalValueOpaque,
+            capacity: usize,
+            ret_len: *mut usize,
+        ) -> bool {
+            let obj: v8::Local<'static, v8::Object> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            let args = v8::GetPropertyNamesArgs {
+                mode: v8::KeyCollectionMode::OwnOnly,
+                property_filter: v8::ONLY_ENUMERABLE,
+                index_filter: v8::IndexFilter::IncludeIndices,
+                key_conversion: v8::KeyConversionMode::ConvertToString,
+            };
+            let keys = match obj.get_own_property_names(scope, args) {
+                Some(v) => v,
+                None => return false,
+            };
+            let len = keys.length() as usize;
+            *ret_len = len;
+            if len > capacity {
+                return true;
+            }
+            for i in 0..len {
+                let name = match keys.get_index(scope, i as u32) {
+                    Some(v) => v,
+                    None => return false,
+                };
+                let value = match obj.get(scope, name) {
+                    Some(v) => v,
+                    None => return false,
+                };
+                *names.add(i) = name.into();
+                *values.add(i) = value.into();
+            }
+            true
+        }
+    }
+
+    pub mod private {
+        use super::*;
+
+        pub unsafe extern "C" fn get_private(
+            ptr: LocalObjectOpaque,
+            scope: *mut HandleScopeOpaque,
+            key: LocalPrivateOpaque,
+            ret: *mut LocalValueOpaque,
+        ) -> bool {
+            let obj: v8::Local<'static, v8::Object> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            let r = obj.get_private(scope, key.into());
+            match r {
+                Some(v) => {
+                    *ret = v.into();
+                    true
+                }
+                None => false,
+            }
+        }
+
+        pub unsafe extern "C" fn set_private(
+            ptr: LocalObjectOpaque,
+            scope: *mut HandleScopeOpaque,
+            key: LocalPrivateOpaque,
+            value: LocalValueOpaque,
+        ) -> OptionBool {
+            let obj: v8::Local<'static, v8::Object> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            obj.set_private(scope, key.into(), value.into()).into()
+        }
+
+        pub unsafe extern "C" fn has_private(
+            ptr: LocalObjectOpaque,
+            scope: *mut HandleScopeOpaque,
+            key: LocalPrivateOpaque,
+        ) -> OptionBool {
+            let obj: v8::Local<'static, v8::Object> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            obj.has_private(scope, key.into()).into()
+        }
+
+        pub unsafe extern "C" fn delete_private(
+            ptr: LocalObjectOpaque,
+            scope: *mut HandleScopeOpaque,
+            key: LocalPrivateOpaque,
+        ) -> OptionBool {
+            let obj: v8::Local<'static, v8::Object> = ptr.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            obj.delete_private(scope, key.into()).into()
+        }
+    }
+}
+
+#[test]
+fn private_round_trip() {
+    crate::with_test_context(|scope| unsafe {
+        let name = v8::String::new(scope, "secret").unwrap();
+        let key = v8::Private::for_api(scope, Some(name));
+        let obj = v8::Object::new(scope);
+        let value: v8::Local<v8::Value> = v8::Integer::new(scope, 42).into();
+        let s = scope as *mut v8::HandleScope<'static> as *mut HandleScopeOpaque;
+
+        assert_eq!(
+            impls::private::has_private(obj.into(), s, key.into()),
+            OptionBool::False
+        );
+        let set = impls::private::set_private(obj.into(), s, key.into(), value.into());
+        assert_eq!(set, OptionBool::True);
+        // a key for the same name is the same key
+        let key = v8::Private::for_api(scope, Some(name));
+        assert_eq!(
+            impls::private::has_private(obj.into(), s, key.into()),
+            OptionBool::True
+        );
+        let mut ret = std::mem::MaybeUninit::<LocalValueOpaque>::uninit();
+        assert!(impls::private::get_private(
+            obj.into(),
+            s,
+            key.into(),
+            ret.as_mut_ptr()
+        ));
+        let got: v8::Local<v8::Value> = ret.assume_init().into();
+        assert!(got.strict_equals(value));
+        // private keys are invisible to script
+        let names = obj
+            .get_own_property_names(scope, Default::default())
+            .unwrap();
+        assert_eq!(names.length(), 0);
+
+        let deleted = impls::private::delete_private(obj.into(), s, key.into());
+        assert_eq!(deleted, OptionBool::True);
+        assert_eq!(
+            impls::private::has_private(obj.into(), s, key.into()),
+            OptionBool::False
+        );
+    });
+}
+
+#[test]
+fn get_own_properties_retry() {
+    fn eval(scope: &mut v8::HandleScope<'static>, source: &str) -> v8::Local<'static, v8::Value> {
+        let source = v8::String::new(scope, source).unwrap();
+        let script = v8::Script::compile(scope, source, None).unwrap();
+        script.run(scope).unwrap()
+    }
+
+    crate::with_test_context(|scope| unsafe {
+        let source = "var calls = 0; ({ get a() { return ++calls }, b: 2 })";
+        let obj: v8::Local<v8::Object> = eval(scope, source).try_into().unwrap();
+        let s = scope as *mut v8::HandleScope<'static> as *mut HandleScopeOpaque;
+        let undefined: v8::Local<v8::Value> = v8::undefined(scope).into();
+        let mut names: [LocalValueOpaque; 2] = [undefined.into(), undefined.into()];
+        let mut values: [LocalValueOpaque; 2] = [undefined.into(), undefined.into()];
+        let mut len = 0;
+        let get = impls::bulk::get_own_properties;
+
+        // too small buffers only report the count, the getter does not run
+        let (n, v) = (names.as_mut_ptr(), values.as_mut_ptr());
+        assert!(get(obj.into(), s, n, v, 1, &mut len));
+        assert_eq!(len, 2);
+        assert_eq!(eval(scope, "calls").int32_value(scope), Some(0));
+
+        assert!(get(obj.into(), s, n, v, 2, &mut len));
+        assert_eq!(len, 2);
+        assert_eq!(eval(scope, "calls").int32_value(scope), Some(1));
+        let [a, b] = values.map(v8::Local::<v8::Value>::from);
+        assert_eq!(a.int32_value(scope), Some(1));
+        assert_eq!(b.int32_value(scope), Some(2));
+        let [a, _] = names.map(v8::Local::<v8::Value>::from);
+        assert_eq!(a.to_rust_string_lossy(scope), "a");
+    });
+}
@@ -0,0 +1,269 @@
+use crate::{
+    impl_transmute_cast, HandleScopeOpaque, LocalFunctionOpaque, LocalValueOpaque, OptionBool,
+};
+use std::ffi::c_void;
+
+#[repr(C)]
+pub struct LocalPromiseOpaque(*mut c_void);
+
+impl_transmute_cast! { LocalPromiseOpaque as v8::Local<'static, v8::Promise> }
+
+#[repr(C)]
+pub struct LocalPromiseResolverOpaque(*mut c_void);
+
+impl_transmute_cast! { LocalPromiseResolverOpaque as v8::Local<'static, v8::PromiseResolver> }
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromiseState {
+    Pending,
+    Fulfilled,
+    Rejected,
+}
+
+impl From<v8::PromiseState> for PromiseState {
+    fn from(value: v8::PromiseState) -> Self {
+        match value {
+            v8::PromiseState::Pending => Self::Pending,
+            v8::PromiseState::Fulfilled => Self::Fulfilled,
+            v8::PromiseState::Rejected => Self::Rejected,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromiseRejectEvent {
+    PromiseRejectWithNoHandler,
+    PromiseHandlerAddedAfterReject,
+    PromiseRejectAfterResolved,
+    PromiseResolveAfterResolved,
+}
+
+impl From<v8::PromiseRejectEvent> for PromiseRejectEvent {
+    fn from(value: v8::PromiseRejectEvent) -> Self {
+        match value {
+            v8::PromiseRejectEvent::PromiseRejectWithNoHandler => Self::PromiseRejectWithNoHandler,
+            v8::PromiseRejectEvent::PromiseHandlerAddedAfterReject => {
+                Self::PromiseHandlerAddedAfterReject
+            }
+            v8::PromiseRejectEvent::PromiseRejectAfterResolved => Self::PromiseRejectAfterResolved,
+            v8::PromiseRejectEvent::PromiseResolveAfterResolved => {
+                Self::PromiseResolveAfterResolved
+            }
+        }
+    }
+}
+
+#[repr(C)]
+pub struct PromiseVTable {
+    as_value: unsafe extern "C" fn(ptr: LocalPromiseOpaque) -> LocalValueOpaque,
+    from_value: unsafe extern "C" fn(value: LocalValueOpaque, ret: *mut LocalPromiseOpaque) -> bool,
+    state: unsafe extern "C" fn(ptr: LocalPromiseOpaque) -> PromiseState,
+    has_handler: unsafe extern "C" fn(ptr: LocalPromiseOpaque) -> bool,
+    result: unsafe extern "C" fn(
+        ptr: LocalPromiseOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalValueOpaque,
+    then: unsafe extern "C" fn(
+        ptr: LocalPromiseOpaque,
+        scope: *mut HandleScopeOpaque,
+        handler: LocalFunctionOpaque,
+        ret: *mut LocalPromiseOpaque,
+    ) -> bool,
+    then2: unsafe extern "C" fn(
+        ptr: LocalPromiseOpaque,
+        scope: *mut HandleScopeOpaque,
+        on_fulfilled: LocalFunctionOpaque,
+        on_rejected: LocalFunctionOpaque,
+        ret: *mut LocalPromiseOpaque,
+    ) -> bool,
+    catch: unsafe extern "C" fn(
+        ptr: LocalPromiseOpaque,
+        scope: *mut HandleScopeOpaque,
+        handler: LocalFunctionOpaque,
+        ret: *mut LocalPromiseOpaque,
+    ) -> bool,
+}
+
+pub const PROMISE_VTABLE: PromiseVTable = PromiseVTable {
+    as_value: impls::promise_as_value,
+    from_value: impls::promise_from_value,
+    state: impls::promise_state,
+    has_handler: impls::promise_has_handler,
+    result: impls::promise_result,
+    then: impls::promise_then,
+    then2: impls::promise_then2,
+    catch: impls::promise_catch,
+};
+
+#[repr(C)]
+pub struct PromiseResolverVTable {
+    ctor: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        ret: *mut LocalPromiseResolverOpaque,
+    ) -> bool,
+    get_promise: unsafe extern "C" fn(
+        ptr: LocalPromiseResolverOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalPromiseOpaque,
+    resolve: unsafe extern "C" fn(
+        ptr: LocalPromiseResolverOpaque,
+        scope: *mut HandleScopeOpaque,
+        value: LocalValueOpaque,
+    ) -> OptionBool,
+    reject: unsafe extern "C" fn(
+        ptr: LocalPromiseResolverOpaque,
+        scope: *mut HandleScopeOpaque,
+        value: LocalValueOpaque,
+    ) -> OptionBool,
+}
+
+pub const PROMISE_RESOLVER_VTABLE: PromiseResolverVTable = PromiseResolverVTable {
+    ctor: impls::promise_resolver_new,
+    get_promise: impls::promise_resolver_get_promise,
+    resolve: impls::promise_resolver_resolve,
+    reject: impls::promise_resolver_reject,
+};
+
+mod impls {
+    use super::*;
+
+    pub unsafe extern "C" fn promise_as_value(ptr: LocalPromiseOpaque) -> LocalValueOpaque {
+        let promise: v8::Local<'static, v8::Promise> = ptr.into();
+        let val: v8::Local<'static, v8::Value> = promise.into();
+        val.into()
+    }
+
+    pub unsafe extern "C" fn promise_from_value(
+        value: LocalValueOpaque,
+        ret: *mut LocalPromiseOpaque,
+    ) -> bool {
+        let val: v8::Local<'static, v8::Value> = value.into();
+        match v8::Local::<v8::Promise>::try_from(val) {
+            Ok(v) => {
+                *ret = v.into();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    pub unsafe extern "C" fn promise_state(ptr: LocalPromiseOpaque) -> PromiseState {
+        let promise: v8::Local<'static, v8::Promise> = ptr.into();
+        promise.state().into()
+    }
+
+    pub unsafe extern "C" fn promise_has_handler(ptr: LocalPromiseOpaque) -> bool {
+        let promise: v8::Local<'static, v8::Promise> = ptr.into();
+        promise.has_handler()
+    }
+
+    pub unsafe extern "C" fn promise_result(
+        ptr: LocalPromiseOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalValueOpaque {
+        let promise: v8::Local<'static, v8::Promise> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        promise.result(scope).into()
+    }
+
+    pub unsafe extern "C" fn promise_then(
+        ptr: LocalPromiseOpaque,
+        scope: *mut HandleScopeOpaque,
+        handler: LocalFunctionOpaque,
+        ret: *mut LocalPromiseOpaque,
+    ) -> bool {
+        let promise: v8::Local<'static, v8::Promise> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        let r = promise.then(scope, handler.into());
+        match r {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn promise_then2(
+        ptr: LocalPromiseOpaque,
+        scope: *mut HandleScopeOpaque,
+        on_fulfilled: LocalFunctionOpaque,
+        on_rejected: LocalFunctionOpaque,
+        ret: *mut LocalPromiseOpaque,
+    ) -> bool {
+        let promise: v8::Local<'static, v8::Promise> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        let r = promise.then2(scope, on_fulfilled.into(), on_rejected.into());
+        match r {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn promise_catch(
+        ptr: LocalPromiseOpaque,
+        scope: *mut HandleScopeOpaque,
+        handler: LocalFunctionOpaque,
+        ret: *mut LocalPromiseOpaque,
+    ) -> bool {
+        let promise: v8::Local<'static, v8::Promise> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        let r = promise.catch(scope, handler.into());
+        match r {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn promise_resolver_new(
+        scope: *mut HandleScopeOpaque,
+        ret: *mut LocalPromiseResolverOpaque,
+    ) -> bool {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        let r = v8::PromiseResolver::new(scope);
+        match r {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn promise_resolver_get_promise(
+        ptr: LocalPromiseResolverOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalPromiseOpaque {
+        let resolver: v8::Local<'static, v8::PromiseResolver> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        resolver.get_promise(scope).into()
+    }
+
+    pub unsafe extern "C" fn promise_resolver_resolve(
+        ptr: LocalPromiseResolverOpaque,
+        scope: *mut HandleScopeOpaque,
+        value: LocalValueOpaque,
+    ) -> OptionBool {
+        let resolver: v8::Local<'static, v8::PromiseResolver> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        resolver.resolve(scope, value.into()).into()
+    }
+
+    pub unsafe extern "C" fn promise_resolver_reject(
+        ptr: LocalPromiseResolverOpaque,
+        scope: *mut HandleScopeOpaque,
+        value: LocalValueOpaque,
+    ) -> OptionBool {
+        let resolver: v8::Local<'static, v8::PromiseResolver> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        resolver.reject(scope, value.into()).into()
+    }
+}
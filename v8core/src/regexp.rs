@@ -0,0 +1,163 @@
+use crate::{
+    impl_transmute_cast, HandleScopeOpaque, Intrinsics, LocalStringOpaque, LocalValueOpaque,
+};
+use std::ffi::c_void;
+
+#[repr(C)]
+pub struct LocalRegExpOpaque(*mut c_void);
+
+impl_transmute_cast! { LocalRegExpOpaque as v8::Local<'static, v8::RegExp> }
+
+// rusty_v8 only exposes the RegExp type, everything else goes through the context's builtins.
+// Instances are created with the constructor captured by `context_new`, but `exec`, `source` and
+// `flags` are ordinary property lookups that script can observe and override.
+#[repr(C)]
+pub struct RegExpVTable {
+    ctor: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        pattern: LocalStringOpaque,
+        flags: LocalStringOpaque,
+        ret: *mut LocalRegExpOpaque,
+    ) -> bool,
+    as_value: unsafe extern "C" fn(ptr: LocalRegExpOpaque) -> LocalValueOpaque,
+    from_value: unsafe extern "C" fn(value: LocalValueOpaque, ret: *mut LocalRegExpOpaque) -> bool,
+    exec: unsafe extern "C" fn(
+        ptr: LocalRegExpOpaque,
+        scope: *mut HandleScopeOpaque,
+        subject: LocalStringOpaque,
+        ret: *mut LocalValueOpaque,
+    ) -> bool,
+    get_source: unsafe extern "C" fn(
+        ptr: LocalRegExpOpaque,
+        scope: *mut HandleScopeOpaque,
+        ret: *mut LocalStringOpaque,
+    ) -> bool,
+    get_flags: unsafe extern "C" fn(
+        ptr: LocalRegExpOpaque,
+        scope: *mut HandleScopeOpaque,
+        ret: *mut LocalStringOpaque,
+    ) -> bool,
+}
+
+pub const REG_EXP_VTABLE: RegExpVTable = RegExpVTable {
+    ctor: impls::reg_exp_new,
+    as_value: impls::reg_exp_as_value,
+    from_value: impls::reg_exp_from_value,
+    exec: impls::reg_exp_exec,
+    get_source: impls::reg_exp_get_source,
+    get_flags: impls::reg_exp_get_flags,
+};
+
+fn get_string(
+    scope: &mut v8::HandleScope<'static>,
+    obj: v8::Local<'static, v8::Object>,
+    name: &str,
+) -> Option<v8::Local<'static, v8::String>> {
+    let key = v8::String::new(scope, name)?;
+    obj.get(scope, key.into())?.to_string(scope)
+}
+
+mod impls {
+    use super::*;
+
+    /// A SyntaxError is left pending on the scope if the pattern or flags are invalid
+    pub unsafe extern "C" fn reg_exp_new(
+        scope: *mut HandleScopeOpaque,
+        pattern: LocalStringOpaque,
+        flags: LocalStringOpaque,
+        ret: *mut LocalRegExpOpaque,
+    ) -> bool {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        let r = (|| {
+            let ctor = Intrinsics::reg_exp(scope)?;
+            let pattern: v8::Local<'static, v8::String> = pattern.into();
+            let flags: v8::Local<'static, v8::String> = flags.into();
+            let obj = ctor.new_instance(scope, &[pattern.into(), flags.into()])?;
+            v8::Local::<v8::RegExp>::try_from(obj).ok()
+        })();
+        match r {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn reg_exp_as_value(ptr: LocalRegExpOpaque) -> LocalValueOpaque {
+        let re: v8::Local<'static, v8::RegExp> = ptr.into();
+        let val: v8::Local<'static, v8::Value> = re.into();
+        val.into()
+    }
+
+    pub unsafe extern "C" fn reg_exp_from_value(
+        value: LocalValueOpaque,
+        ret: *mut LocalRegExpOpaque,
+    ) -> bool {
+        let val: v8::Local<'static, v8::Value> = value.into();
+        match v8::Local::<v8::RegExp>::try_from(val) {
+            Ok(v) => {
+                *ret = v.into();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// The result is null when nothing matched, otherwise the match array of the `exec` method,
+    /// which is looked up on the instance and can be replaced by script
+    pub unsafe extern "C" fn reg_exp_exec(
+        ptr: LocalRegExpOpaque,
+        scope: *mut HandleScopeOpaque,
+        subject: LocalStringOpaque,
+        ret: *mut LocalValueOpaque,
+    ) -> bool {
+        let re: v8::Local<'static, v8::RegExp> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        let r = (|| {
+            let key = v8::String::new(scope, "exec")?;
+            let exec = v8::Local::<v8::Function>::try_from(re.get(scope, key.into())?).ok()?;
+            let subject: v8::Local<'static, v8::String> = subject.into();
+            exec.call(scope, re.into(), &[subject.into()])
+        })();
+        match r {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn reg_exp_get_source(
+        ptr: LocalRegExpOpaque,
+        scope: *mut HandleScopeOpaque,
+        ret: *mut LocalStringOpaque,
+    ) -> bool {
+        let re: v8::Local<'static, v8::RegExp> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        match get_string(scope, re.into(), "source") {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn reg_exp_get_flags(
+        ptr: LocalRegExpOpaque,
+        scope: *mut HandleScopeOpaque,
+        ret: *mut LocalStringOpaque,
+    ) -> bool {
+        let re: v8::Local<'static, v8::RegExp> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        match get_string(scope, re.into(), "flags") {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+}
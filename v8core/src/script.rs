@@ -1,10 +1,37 @@
 use crate::{impl_transmute_cast, HandleScopeOpaque, LocalStringOpaque, LocalValueOpaque};
+use std::ffi::c_void;
 
 #[repr(C)]
 pub struct LocalScriptOpaque(usize);
 
 impl_transmute_cast! { LocalScriptOpaque as v8::Local<'static, v8::Script> }
 
+#[repr(C)]
+pub struct LocalUnboundScriptOpaque(*mut c_void);
+
+impl_transmute_cast! { LocalUnboundScriptOpaque as v8::Local<'static, v8::UnboundScript> }
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptRunStatus {
+    Success,
+    Exception,
+    TimedOut,
+    /// Terminated by something other than the timeout, e.g. `IsolateHandle::terminate_execution`
+    Terminated,
+}
+
+impl ScriptRunStatus {
+    pub(crate) fn new(succeeded: bool, terminated: bool, timed_out: bool) -> Self {
+        match (succeeded, terminated, timed_out) {
+            (true, _, _) => Self::Success,
+            (false, false, _) => Self::Exception,
+            (false, true, true) => Self::TimedOut,
+            (false, true, false) => Self::Terminated,
+        }
+    }
+}
+
 #[repr(C)]
 pub struct ScriptVTable {
     ctor_compile: unsafe extern "C" fn(
@@ -17,15 +44,42 @@ pub struct ScriptVTable {
         scope: *mut HandleScopeOpaque,
         ret: *mut LocalValueOpaque,
     ) -> bool,
+    run_with_timeout: unsafe extern "C" fn(
+        ptr: LocalScriptOpaque,
+        scope: *mut HandleScopeOpaque,
+        timeout_ms: u64,
+        ret: *mut LocalValueOpaque,
+    ) -> ScriptRunStatus,
+    get_unbound_script: unsafe extern "C" fn(
+        ptr: LocalScriptOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalUnboundScriptOpaque,
 }
 
 pub const SCRIPT_VTABLE: ScriptVTable = ScriptVTable {
     ctor_compile: impls::script_compile,
     run: impls::script_run,
+    run_with_timeout: impls::script_run_with_timeout,
+    get_unbound_script: impls::script_get_unbound_script,
+};
+
+#[repr(C)]
+pub struct UnboundScriptVTable {
+    bind_to_current_context: unsafe extern "C" fn(
+        ptr: LocalUnboundScriptOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalScriptOpaque,
+}
+
+pub const UNBOUND_SCRIPT_VTABLE: UnboundScriptVTable = UnboundScriptVTable {
+    bind_to_current_context: impls::unbound_script_bind_to_current_context,
 };
 
 mod impls {
     use super::*;
+    use std::sync::mpsc::{self, RecvTimeoutError};
+    use std::thread;
+    use std::time::Duration;
 
     pub unsafe extern "C" fn script_compile(
         scope: *mut HandleScopeOpaque,
@@ -60,4 +114,99 @@ mod impls {
             None => false,
         }
     }
+
+    /// Runs the script while a watchdog thread terminates the execution once the budget is used up.
+    /// Each call spawns and joins one OS thread. Only a termination caused by the watchdog is
+    /// cancelled before returning, a termination requested by the host is left in place and
+    /// reported as `Terminated`. Exceptions thrown by the script are rethrown to the caller.
+    pub unsafe extern "C" fn script_run_with_timeout(
+        ptr: LocalScriptOpaque,
+        scope: *mut HandleScopeOpaque,
+        timeout_ms: u64,
+        ret: *mut LocalValueOpaque,
+    ) -> ScriptRunStatus {
+        let script: v8::Local<'static, v8::Script> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+
+        let handle = scope.thread_safe_handle();
+        let (done, wait) = mpsc::channel::<()>();
+        let watchdog =
+            thread::spawn(
+                move || match wait.recv_timeout(Duration::from_millis(timeout_ms)) {
+                    // do not take over a termination the host already requested
+                    Err(RecvTimeoutError::Timeout) if !handle.is_execution_terminating() => {
+                        handle.terminate_execution()
+                    }
+                    _ => false,
+                },
+            );
+
+        let tc = &mut v8::TryCatch::new(scope);
+        // keep reporting uncaught exceptions to message listeners as a plain run does
+        tc.set_verbose(true);
+        let r = script.run(tc);
+        drop(done);
+        let timed_out = watchdog.join().unwrap_or(false);
+        let terminated = tc.has_terminated();
+        if timed_out {
+            tc.cancel_terminate_execution();
+        }
+
+        let status = ScriptRunStatus::new(r.is_some(), terminated, timed_out);
+        match r {
+            Some(v) => *ret = v.into(),
+            None if !terminated => {
+                tc.rethrow();
+            }
+            None => {}
+        }
+        status
+    }
+
+    pub unsafe extern "C" fn script_get_unbound_script(
+        ptr: LocalScriptOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalUnboundScriptOpaque {
+        let script: v8::Local<'static, v8::Script> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        script.get_unbound_script(scope).into()
+    }
+
+    /// The unbound script can be bound into any number of contexts, each binding runs independently
+    pub unsafe extern "C" fn unbound_script_bind_to_current_context(
+        ptr: LocalUnboundScriptOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalScriptOpaque {
+        let script: v8::Local<'static, v8::UnboundScript> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        script.bind_to_current_context(scope).into()
+    }
+}
+
+#[test]
+fn run_with_timeout() {
+    fn run(scope: &mut v8::HandleScope<'static>, source: &str) -> ScriptRunStatus {
+        let source = v8::String::new(scope, source).unwrap();
+        let script = v8::Script::compile(scope, source, None).unwrap();
+        let mut ret = std::mem::MaybeUninit::<LocalValueOpaque>::uninit();
+        let s = scope as *mut v8::HandleScope<'static> as *mut HandleScopeOpaque;
+        unsafe { impls::script_run_with_timeout(script.into(), s, 100, ret.as_mut_ptr()) }
+    }
+
+    crate::with_test_context(|scope| {
+        assert_eq!(run(scope, "1 + 1"), ScriptRunStatus::Success);
+        assert_eq!(run(scope, "while (true) {}"), ScriptRunStatus::TimedOut);
+        // the watchdog's termination is cancelled, so the isolate keeps running scripts
+        assert_eq!(run(scope, "1 + 1"), ScriptRunStatus::Success);
+
+        let tc = &mut v8::TryCatch::new(scope);
+        assert_eq!(run(tc, "throw 1"), ScriptRunStatus::Exception);
+        assert!(tc.has_caught());
+        tc.reset();
+
+        // a termination requested by the host is not mistaken for the timeout
+        tc.terminate_execution();
+        assert_eq!(run(tc, "while (true) {}"), ScriptRunStatus::Terminated);
+        tc.cancel_terminate_execution();
+    });
 }
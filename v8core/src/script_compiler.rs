@@ -0,0 +1,321 @@
+use crate::{
+    impl_transmute_cast, ByteSlice, HandleScopeOpaque, LocalFunctionOpaque, LocalObjectOpaque,
+    LocalScriptOpaque, LocalStringOpaque, LocalUnboundScriptOpaque,
+};
+use v8::script_compiler::{CachedData, Source};
+
+#[repr(C)]
+pub struct CachedDataOpaque(usize);
+
+impl_transmute_cast! { CachedDataOpaque as v8::UniqueRef<CachedData<'static>> }
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompileOptions {
+    NoCompileOptions,
+    ConsumeCodeCache,
+    EagerCompile,
+}
+
+impl From<CompileOptions> for v8::script_compiler::CompileOptions {
+    fn from(value: CompileOptions) -> Self {
+        match value {
+            CompileOptions::NoCompileOptions => Self::NoCompileOptions,
+            CompileOptions::ConsumeCodeCache => Self::ConsumeCodeCache,
+            CompileOptions::EagerCompile => Self::EagerCompile,
+        }
+    }
+}
+
+#[repr(C)]
+pub struct ScriptCompilerVTable {
+    compile: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        source: LocalStringOpaque,
+        options: CompileOptions,
+        cached_data: *const ByteSlice,
+        ret: *mut LocalScriptOpaque,
+        cache_rejected: *mut bool,
+    ) -> bool,
+    compile_unbound: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        source: LocalStringOpaque,
+        options: CompileOptions,
+        cached_data: *const ByteSlice,
+        ret: *mut LocalUnboundScriptOpaque,
+        cache_rejected: *mut bool,
+    ) -> bool,
+    compile_function: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        source: LocalStringOpaque,
+        arguments: *const LocalStringOpaque,
+        arguments_len: usize,
+        context_extensions: *const LocalObjectOpaque,
+        context_extensions_len: usize,
+        options: CompileOptions,
+        cached_data: *const ByteSlice,
+        ret: *mut LocalFunctionOpaque,
+        cache_rejected: *mut bool,
+    ) -> bool,
+    cached_data_version_tag: unsafe extern "C" fn() -> u32,
+
+    // code cache
+    create_code_cache_unbound_script:
+        unsafe extern "C" fn(ptr: LocalUnboundScriptOpaque, ret: *mut CachedDataOpaque) -> bool,
+    create_code_cache_function:
+        unsafe extern "C" fn(ptr: LocalFunctionOpaque, ret: *mut CachedDataOpaque) -> bool,
+    cached_data_drop: unsafe extern "C" fn(ptr: CachedDataOpaque),
+    cached_data_bytes: unsafe extern "C" fn(ptr: *const CachedDataOpaque) -> ByteSlice,
+}
+
+pub const SCRIPT_COMPILER_VTABLE: ScriptCompilerVTable = ScriptCompilerVTable {
+    compile: impls::compile,
+    compile_unbound: impls::compile_unbound,
+    compile_function: impls::compile_function,
+    cached_data_version_tag: impls::cached_data_version_tag,
+
+    // code cache
+    create_code_cache_unbound_script: impls::code_cache::create_code_cache_unbound_script,
+    create_code_cache_function: impls::code_cache::create_code_cache_function,
+    cached_data_drop: impls::code_cache::cached_data_drop,
+    cached_data_bytes: impls::code_cache::cached_data_bytes,
+};
+
+// rusty_v8 drops the source inside its compile functions, which hides whether v8 rejected the
+// supplied code cache, so the bindings are called directly to inspect the source afterwards.
+// The signatures match binding.cc of v8 0.69 and must be checked again when the v8 crate is bumped.
+extern "C" {
+    fn v8__ScriptCompiler__Compile(
+        context: *const v8::Context,
+        source: *mut Source,
+        options: v8::script_compiler::CompileOptions,
+        no_cache_reason: v8::script_compiler::NoCacheReason,
+    ) -> *const v8::Script;
+    fn v8__ScriptCompiler__CompileUnboundScript(
+        isolate: *mut v8::Isolate,
+        source: *mut Source,
+        options: v8::script_compiler::CompileOptions,
+        no_cache_reason: v8::script_compiler::NoCacheReason,
+    ) -> *const v8::UnboundScript;
+    fn v8__ScriptCompiler__CompileFunction(
+        context: *const v8::Context,
+        source: *mut Source,
+        arguments_count: usize,
+        arguments: *const LocalStringOpaque,
+        context_extensions_count: usize,
+        context_extensions: *const LocalObjectOpaque,
+        options: v8::script_compiler::CompileOptions,
+        no_cache_reason: v8::script_compiler::NoCacheReason,
+    ) -> *const v8::Function;
+}
+
+/// Same layout as the start of `v8::CachedData` in v8 0.69, whose `rejected` flag is private
+#[repr(C)]
+struct CachedDataHeader {
+    data: *const u8,
+    length: i32,
+    rejected: bool,
+}
+
+// binding.cc of v8 0.69 asserts the same size and offsets for the C++ type
+const _: () = assert!(std::mem::size_of::<CachedData<'static>>() == 24);
+const _: () = assert!(std::mem::offset_of!(CachedDataHeader, data) == 0);
+const _: () = assert!(std::mem::offset_of!(CachedDataHeader, length) == 8);
+const _: () = assert!(std::mem::offset_of!(CachedDataHeader, rejected) == 12);
+
+/// Consuming a code cache without cached data throws a TypeError instead of reaching v8
+unsafe fn check_options(
+    scope: &mut v8::HandleScope<'static>,
+    options: CompileOptions,
+    cached_data: *const ByteSlice,
+) -> bool {
+    if options != CompileOptions::ConsumeCodeCache || !cached_data.is_null() {
+        return true;
+    }
+    let message = v8::String::new(scope, "ConsumeCodeCache requires cached data").unwrap();
+    let exception = v8::Exception::type_error(scope, message);
+    scope.throw_exception(exception);
+    false
+}
+
+pub(crate) unsafe fn new_source(
+    source: LocalStringOpaque,
+    cached_data: *const ByteSlice,
+) -> Source {
+    match cached_data.as_ref() {
+        // the cached data only borrows the host buffer, which outlives the compilation
+        Some(cached_data) => {
+            let bytes: &'static [u8] = std::slice::from_raw_parts(cached_data.ptr, cached_data.len);
+            Source::new_with_cached_data(source.into(), None, CachedData::new(bytes))
+        }
+        None => Source::new(source.into(), None),
+    }
+}
+
+pub(crate) unsafe fn is_cache_rejected(source: &Source) -> bool {
+    match source.get_cached_data() {
+        Some(cached_data) => {
+            let header = &*(cached_data as *const CachedData as *const CachedDataHeader);
+            header.rejected
+        }
+        None => false,
+    }
+}
+
+mod impls {
+    use super::*;
+
+    pub unsafe extern "C" fn compile(
+        scope: *mut HandleScopeOpaque,
+        source: LocalStringOpaque,
+        options: CompileOptions,
+        cached_data: *const ByteSlice,
+        ret: *mut LocalScriptOpaque,
+        cache_rejected: *mut bool,
+    ) -> bool {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        if !check_options(scope, options, cached_data) {
+            return false;
+        }
+        let mut source = new_source(source, cached_data);
+        let context = scope.get_current_context();
+        let s = v8__ScriptCompiler__Compile(
+            &*context,
+            &mut source,
+            options.into(),
+            v8::script_compiler::NoCacheReason::NoReason,
+        );
+        if let Some(cache_rejected) = cache_rejected.as_mut() {
+            *cache_rejected = is_cache_rejected(&source);
+        }
+        let s: Option<v8::Local<'static, v8::Script>> = std::mem::transmute(s);
+        match s {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn compile_unbound(
+        scope: *mut HandleScopeOpaque,
+        source: LocalStringOpaque,
+        options: CompileOptions,
+        cached_data: *const ByteSlice,
+        ret: *mut LocalUnboundScriptOpaque,
+        cache_rejected: *mut bool,
+    ) -> bool {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        if !check_options(scope, options, cached_data) {
+            return false;
+        }
+        let mut source = new_source(source, cached_data);
+        let s = v8__ScriptCompiler__CompileUnboundScript(
+            scope as &mut v8::Isolate,
+            &mut source,
+            options.into(),
+            v8::script_compiler::NoCacheReason::NoReason,
+        );
+        if let Some(cache_rejected) = cache_rejected.as_mut() {
+            *cache_rejected = is_cache_rejected(&source);
+        }
+        let s: Option<v8::Local<'static, v8::UnboundScript>> = std::mem::transmute(s);
+        match s {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The source is compiled as the body of a function taking `arguments`, the names of
+    /// `context_extensions` properties are in scope as if the body were inside `with` blocks
+    pub unsafe extern "C" fn compile_function(
+        scope: *mut HandleScopeOpaque,
+        source: LocalStringOpaque,
+        arguments: *const LocalStringOpaque,
+        arguments_len: usize,
+        context_extensions: *const LocalObjectOpaque,
+        context_extensions_len: usize,
+        options: CompileOptions,
+        cached_data: *const ByteSlice,
+        ret: *mut LocalFunctionOpaque,
+        cache_rejected: *mut bool,
+    ) -> bool {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        if !check_options(scope, options, cached_data) {
+            return false;
+        }
+        let mut source = new_source(source, cached_data);
+        let context = scope.get_current_context();
+        let f = v8__ScriptCompiler__CompileFunction(
+            &*context,
+            &mut source,
+            arguments_len,
+            arguments,
+            context_extensions_len,
+            context_extensions,
+            options.into(),
+            v8::script_compiler::NoCacheReason::NoReason,
+        );
+        if let Some(cache_rejected) = cache_rejected.as_mut() {
+            *cache_rejected = is_cache_rejected(&source);
+        }
+        let f: Option<v8::Local<'static, v8::Function>> = std::mem::transmute(f);
+        match f {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn cached_data_version_tag() -> u32 {
+        v8::script_compiler::cached_data_version_tag()
+    }
+
+    pub mod code_cache {
+        use super::*;
+
+        pub unsafe extern "C" fn create_code_cache_unbound_script(
+            ptr: LocalUnboundScriptOpaque,
+            ret: *mut CachedDataOpaque,
+        ) -> bool {
+            let script: v8::Local<'static, v8::UnboundScript> = ptr.into();
+            match script.create_code_cache() {
+                Some(v) => {
+                    *ret = v.into();
+                    true
+                }
+                None => false,
+            }
+        }
+
+        pub unsafe extern "C" fn create_code_cache_function(
+            ptr: LocalFunctionOpaque,
+            ret: *mut CachedDataOpaque,
+        ) -> bool {
+            let func: v8::Local<'static, v8::Function> = ptr.into();
+            match func.create_code_cache() {
+                Some(v) => {
+                    *ret = v.into();
+                    true
+                }
+                None => false,
+            }
+        }
+
+        pub unsafe extern "C" fn cached_data_drop(ptr: CachedDataOpaque) {
+            let data: v8::UniqueRef<CachedData<'static>> = ptr.into();
+            drop(data)
+        }
+
+        pub unsafe extern "C" fn cached_data_bytes(ptr: *const CachedDataOpaque) -> ByteSlice {
+            let data = &*(ptr as *const v8::UniqueRef<CachedData<'static>>);
+            ByteSlice::new(data)
+        }
+    }
+}
@@ -0,0 +1,288 @@
+use crate::{
+    host_function_callback, impl_transmute_cast, ByteSlice, IsolateOpaque, LocalContextOpaque,
+    OwnedIsolateOpaque,
+};
+use std::ffi::c_void;
+
+#[repr(C)]
+pub struct StartupDataOpaque(usize, usize);
+
+impl_transmute_cast! { StartupDataOpaque as v8::StartupData }
+
+#[repr(C)]
+pub struct SnapshotCreatorOpaque(usize);
+
+impl_transmute_cast! { SnapshotCreatorOpaque as Box<SnapshotCreator> }
+
+/// v8 keeps using the external references until the isolate is gone, so the table lives next to
+/// the isolate and is dropped after it
+struct SnapshotCreator {
+    isolate: v8::OwnedIsolate,
+    _external_references: Box<v8::ExternalReferences>,
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FunctionCodeHandling {
+    Clear,
+    Keep,
+}
+
+impl From<FunctionCodeHandling> for v8::FunctionCodeHandling {
+    fn from(value: FunctionCodeHandling) -> Self {
+        match value {
+            FunctionCodeHandling::Clear => Self::Clear,
+            FunctionCodeHandling::Keep => Self::Keep,
+        }
+    }
+}
+
+/// Builds the external references table for a snapshot, the host function callback always comes
+/// first so the host only has to list the addresses of its own callbacks and data pointers,
+/// in the same order when creating and when deserializing the snapshot.
+pub(crate) unsafe fn external_references(
+    refs: *const *const c_void,
+    len: usize,
+) -> v8::ExternalReferences {
+    let refs = if refs.is_null() || len == 0 {
+        &[]
+    } else {
+        std::slice::from_raw_parts(refs, len)
+    };
+    let refs = std::iter::once(v8::ExternalReference {
+        function: host_function_callback(),
+    })
+    .chain(refs.iter().map(|&pointer| v8::ExternalReference {
+        pointer: pointer as *mut c_void,
+    }))
+    .collect::<Vec<_>>();
+    v8::ExternalReferences::new(&refs)
+}
+
+#[repr(C)]
+pub struct SnapshotCreatorVTable {
+    ctor: unsafe extern "C" fn(
+        external_references: *const *const c_void,
+        external_references_len: usize,
+    ) -> SnapshotCreatorOpaque,
+    drop: unsafe extern "C" fn(ptr: SnapshotCreatorOpaque),
+    isolate: unsafe extern "C" fn(ptr: *mut SnapshotCreatorOpaque) -> *mut OwnedIsolateOpaque,
+    set_default_context: unsafe extern "C" fn(ptr: *mut IsolateOpaque, ctx: LocalContextOpaque),
+    add_context: unsafe extern "C" fn(ptr: *mut IsolateOpaque, ctx: LocalContextOpaque) -> usize,
+    create_blob: unsafe extern "C" fn(
+        ptr: SnapshotCreatorOpaque,
+        function_code_handling: FunctionCodeHandling,
+        ret: *mut StartupDataOpaque,
+    ) -> bool,
+
+    // startup data
+    blob_drop: unsafe extern "C" fn(ptr: StartupDataOpaque),
+    blob_bytes: unsafe extern "C" fn(ptr: *const StartupDataOpaque) -> ByteSlice,
+}
+
+pub const SNAPSHOT_CREATOR_VTABLE: SnapshotCreatorVTable = SnapshotCreatorVTable {
+    ctor: impls::snapshot_creator_new,
+    drop: impls::snapshot_creator_drop,
+    isolate: impls::snapshot_creator_isolate,
+    set_default_context: impls::snapshot_creator_set_default_context,
+    add_context: impls::snapshot_creator_add_context,
+    create_blob: impls::snapshot_creator_create_blob,
+
+    // startup data
+    blob_drop: impls::startup_data_drop,
+    blob_bytes: impls::startup_data_bytes,
+};
+
+mod impls {
+    use super::*;
+
+    pub unsafe extern "C" fn snapshot_creator_new(
+        external_references: *const *const c_void,
+        external_references_len: usize,
+    ) -> SnapshotCreatorOpaque {
+        let refs = Box::new(super::external_references(
+            external_references,
+            external_references_len,
+        ));
+        // the table is only freed after the isolate, see `SnapshotCreator`
+        let static_refs = &*(refs.as_ref() as *const v8::ExternalReferences);
+        let creator = Box::new(SnapshotCreator {
+            isolate: v8::Isolate::snapshot_creator(Some(static_refs)),
+            _external_references: refs,
+        });
+        creator.into()
+    }
+
+    pub unsafe extern "C" fn snapshot_creator_drop(ptr: SnapshotCreatorOpaque) {
+        let creator: Box<SnapshotCreator> = ptr.into();
+        drop(creator)
+    }
+
+    /// The isolate is used like any other one until `create_blob` consumes the creator,
+    /// it must not be dropped on its own
+    pub unsafe extern "C" fn snapshot_creator_isolate(
+        ptr: *mut SnapshotCreatorOpaque,
+    ) -> *mut OwnedIsolateOpaque {
+        let creator = &mut *(ptr as *mut Box<SnapshotCreator>);
+        &mut creator.isolate as *mut v8::OwnedIsolate as *mut OwnedIsolateOpaque
+    }
+
+    /// The context's slots are cleared since their weak handles cannot be snapshotted
+    pub unsafe extern "C" fn snapshot_creator_set_default_context(
+        ptr: *mut IsolateOpaque,
+        ctx: LocalContextOpaque,
+    ) {
+        let iso = &mut *(ptr as *mut v8::Isolate);
+        let ctx: v8::Local<'static, v8::Context> = ctx.into();
+        ctx.clear_all_slots(iso);
+        iso.set_default_context(ctx)
+    }
+
+    /// The context's slots are cleared since their weak handles cannot be snapshotted
+    pub unsafe extern "C" fn snapshot_creator_add_context(
+        ptr: *mut IsolateOpaque,
+        ctx: LocalContextOpaque,
+    ) -> usize {
+        let iso = &mut *(ptr as *mut v8::Isolate);
+        let ctx: v8::Local<'static, v8::Context> = ctx.into();
+        ctx.clear_all_slots(iso);
+        iso.add_context(ctx)
+    }
+
+    /// Must not be called while any scope of the isolate is alive
+    pub unsafe extern "C" fn snapshot_creator_create_blob(
+        ptr: SnapshotCreatorOpaque,
+        function_code_handling: FunctionCodeHandling,
+        ret: *mut StartupDataOpaque,
+    ) -> bool {
+        let creator: Box<SnapshotCreator> = ptr.into();
+        let SnapshotCreator {
+            isolate,
+            _external_references: external_references,
+        } = *creator;
+        let r = isolate.create_blob(function_code_handling.into());
+        drop(external_references);
+        match r {
+            Some(v) => {
+                *ret = v.into();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub unsafe extern "C" fn startup_data_drop(ptr: StartupDataOpaque) {
+        let data: v8::StartupData = ptr.into();
+        drop(data)
+    }
+
+    pub unsafe extern "C" fn startup_data_bytes(ptr: *const StartupDataOpaque) -> ByteSlice {
+        let data = &*(ptr as *const v8::StartupData);
+        ByteSlice::new(data)
+    }
+}
+
+/// Runs `build` in the isolate of a new snapshot creator and snapshots the context it returns as
+/// the default context, through the same calls the host makes
+#[cfg(test)]
+fn create_test_blob(
+    refs: &[*const c_void],
+    build: impl FnOnce(&mut v8::HandleScope<'static, ()>) -> v8::Local<'static, v8::Context>,
+) -> v8::StartupData {
+    crate::init_test_v8();
+    unsafe {
+        let mut creator = impls::snapshot_creator_new(refs.as_ptr(), refs.len());
+        {
+            let isolate = impls::snapshot_creator_isolate(&mut creator) as *mut v8::OwnedIsolate;
+            let scope = &mut v8::HandleScope::new(&mut *isolate);
+            let scope = scope as *mut v8::HandleScope<()> as *mut crate::HandleScopeOpaque;
+            let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
+            let context = build(scope);
+            let iso = &mut **scope as *mut v8::Isolate as *mut IsolateOpaque;
+            impls::snapshot_creator_set_default_context(iso, context.into());
+        }
+        let mut blob = std::mem::MaybeUninit::<StartupDataOpaque>::uninit();
+        assert!(impls::snapshot_creator_create_blob(
+            creator,
+            FunctionCodeHandling::Keep,
+            blob.as_mut_ptr()
+        ));
+        blob.assume_init().into()
+    }
+}
+
+#[test]
+fn snapshot_with_host_function() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    unsafe extern "C" fn count(
+        _scope: *mut crate::HandleScopeOpaque,
+        _info: *mut crate::FunctionCallbackInfoOpaque,
+        data: *mut c_void,
+    ) {
+        (*(data as *const AtomicUsize)).fetch_add(1, Ordering::SeqCst);
+    }
+
+    // both the callback and its data are stored in the snapshot as external references
+    let data = &CALLS as *const AtomicUsize as *mut c_void;
+    let refs = [count as *const c_void, data as *const c_void];
+    let blob = create_test_blob(&refs, |scope| {
+        let context = v8::Context::new(scope);
+        let scope = &mut v8::ContextScope::new(scope, context);
+        let function = crate::new_host_function(scope, count, data).unwrap();
+        let key = v8::String::new(scope, "count").unwrap();
+        context
+            .global(scope)
+            .set(scope, key.into(), function.into());
+        context
+    });
+
+    let refs = unsafe { external_references(refs.as_ptr(), refs.len()) };
+    let params = v8::CreateParams::default()
+        .snapshot_blob(blob.to_vec())
+        .external_references(refs);
+    let isolate = &mut v8::Isolate::new(params);
+    let scope = &mut v8::HandleScope::new(isolate);
+    let context = v8::Context::new(scope);
+    let scope = &mut v8::ContextScope::new(scope, context);
+    let code = v8::String::new(scope, "count(); count()").unwrap();
+    v8::Script::compile(scope, code, None)
+        .unwrap()
+        .run(scope)
+        .unwrap();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn snapshot_context_with_intrinsics() {
+    let blob = create_test_blob(&[], |scope| {
+        let context = crate::new_context(scope);
+        let scope = &mut v8::ContextScope::new(scope, context);
+        let code = v8::String::new(scope, "globalThis.answer = 42").unwrap();
+        v8::Script::compile(scope, code, None)
+            .unwrap()
+            .run(scope)
+            .unwrap();
+        context
+    });
+
+    let refs = unsafe { external_references(std::ptr::null(), 0) };
+    let params = v8::CreateParams::default()
+        .snapshot_blob(blob.to_vec())
+        .external_references(refs);
+    let isolate = &mut v8::Isolate::new(params);
+    let scope = &mut v8::HandleScope::new(isolate);
+    let scope = scope as *mut v8::HandleScope<()> as *mut crate::HandleScopeOpaque;
+    let scope = unsafe { &mut *(scope as *mut v8::HandleScope<'static, ()>) };
+    let context = crate::new_context(scope);
+    let scope = &mut v8::ContextScope::new(scope, context);
+    let code = v8::String::new(scope, "answer").unwrap();
+    let answer = v8::Script::compile(scope, code, None)
+        .unwrap()
+        .run(scope)
+        .unwrap();
+    assert_eq!(answer.int32_value(scope), Some(42));
+    assert!(crate::Intrinsics::reg_exp(scope).is_some());
+}
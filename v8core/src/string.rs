@@ -39,7 +39,7 @@ mod impls {
     ) -> bool {
         let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
 
-        let s = v8::String::new_external_twobyte_static(scope, &*buffer);
+        let s = v8::String::new_from_two_byte(scope, &*buffer, v8::NewStringType::Normal);
         match s {
             Some(v) => {
                 *ret = v.into();
@@ -68,3 +68,19 @@ mod impls {
         s.write(scope, &mut *buffer, start, options)
     }
 }
+
+#[test]
+fn new_utf16_copies_the_buffer() {
+    crate::init_test_v8();
+    let isolate = &mut v8::Isolate::new(Default::default());
+    let scope = &mut v8::HandleScope::new(isolate);
+
+    let mut buffer: Vec<u16> = "host".encode_utf16().collect();
+    let mut ret = LocalStringOpaque(std::ptr::null_mut());
+    let scope_ptr = scope as *mut v8::HandleScope<()> as *mut HandleScopeOpaque;
+    assert!(unsafe { impls::string_new_utf16(scope_ptr, &CharSlice::new(&buffer), &mut ret) });
+    // the host may reuse its buffer as soon as the call returns
+    buffer.fill(0);
+    let s: v8::Local<v8::String> = ret.into();
+    assert_eq!(s.to_rust_string_lossy(scope), "host");
+}
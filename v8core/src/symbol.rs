@@ -0,0 +1,203 @@
+use crate::{impl_transmute_cast, HandleScopeOpaque, LocalStringOpaque, LocalValueOpaque};
+use std::ffi::c_void;
+
+#[repr(C)]
+pub struct LocalSymbolOpaque(*mut c_void);
+
+impl_transmute_cast! { LocalSymbolOpaque as v8::Local<'static, v8::Symbol> }
+
+#[repr(C)]
+pub struct LocalPrivateOpaque(*mut c_void);
+
+impl_transmute_cast! { LocalPrivateOpaque as v8::Local<'static, v8::Private> }
+
+#[repr(C)]
+pub struct SymbolVTable {
+    ctor: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+    ctor_description: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        description: LocalStringOpaque,
+    ) -> LocalSymbolOpaque,
+    for_key: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        key: LocalStringOpaque,
+    ) -> LocalSymbolOpaque,
+    description: unsafe extern "C" fn(
+        ptr: LocalSymbolOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalValueOpaque,
+    as_value: unsafe extern "C" fn(ptr: LocalSymbolOpaque) -> LocalValueOpaque,
+    from_value: unsafe extern "C" fn(value: LocalValueOpaque, ret: *mut LocalSymbolOpaque) -> bool,
+
+    // well known
+    async_iterator: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+    has_instance: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+    is_concat_spreadable: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+    iterator: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+    match_: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+    replace: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+    search: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+    split: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+    to_primitive: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+    to_string_tag: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+    unscopables: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque,
+}
+
+pub const SYMBOL_VTABLE: SymbolVTable = SymbolVTable {
+    ctor: impls::symbol_new,
+    ctor_description: impls::symbol_new_description,
+    for_key: impls::symbol_for_key,
+    description: impls::symbol_description,
+    as_value: impls::symbol_as_value,
+    from_value: impls::symbol_from_value,
+
+    // well known
+    async_iterator: impls::well_known::async_iterator,
+    has_instance: impls::well_known::has_instance,
+    is_concat_spreadable: impls::well_known::is_concat_spreadable,
+    iterator: impls::well_known::iterator,
+    match_: impls::well_known::match_,
+    replace: impls::well_known::replace,
+    search: impls::well_known::search,
+    split: impls::well_known::split,
+    to_primitive: impls::well_known::to_primitive,
+    to_string_tag: impls::well_known::to_string_tag,
+    unscopables: impls::well_known::unscopables,
+};
+
+#[repr(C)]
+pub struct PrivateVTable {
+    ctor: unsafe extern "C" fn(scope: *mut HandleScopeOpaque) -> LocalPrivateOpaque,
+    ctor_name: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        name: LocalStringOpaque,
+    ) -> LocalPrivateOpaque,
+    for_key: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        key: LocalStringOpaque,
+    ) -> LocalPrivateOpaque,
+    name: unsafe extern "C" fn(
+        ptr: LocalPrivateOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalValueOpaque,
+}
+
+pub const PRIVATE_VTABLE: PrivateVTable = PrivateVTable {
+    ctor: impls::private_new,
+    ctor_name: impls::private_new_name,
+    for_key: impls::private_for_key,
+    name: impls::private_name,
+};
+
+mod impls {
+    use super::*;
+
+    pub unsafe extern "C" fn symbol_new(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
+        v8::Symbol::new(scope, None).into()
+    }
+
+    pub unsafe extern "C" fn symbol_new_description(
+        scope: *mut HandleScopeOpaque,
+        description: LocalStringOpaque,
+    ) -> LocalSymbolOpaque {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
+        v8::Symbol::new(scope, Some(description.into())).into()
+    }
+
+    pub unsafe extern "C" fn symbol_for_key(
+        scope: *mut HandleScopeOpaque,
+        key: LocalStringOpaque,
+    ) -> LocalSymbolOpaque {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
+        v8::Symbol::for_global(scope, key.into()).into()
+    }
+
+    pub unsafe extern "C" fn symbol_description(
+        ptr: LocalSymbolOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalValueOpaque {
+        let sym: v8::Local<'static, v8::Symbol> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
+        sym.description(scope).into()
+    }
+
+    pub unsafe extern "C" fn symbol_as_value(ptr: LocalSymbolOpaque) -> LocalValueOpaque {
+        let sym: v8::Local<'static, v8::Symbol> = ptr.into();
+        let val: v8::Local<'static, v8::Value> = sym.into();
+        val.into()
+    }
+
+    pub unsafe extern "C" fn symbol_from_value(
+        value: LocalValueOpaque,
+        ret: *mut LocalSymbolOpaque,
+    ) -> bool {
+        let val: v8::Local<'static, v8::Value> = value.into();
+        match v8::Local::<v8::Symbol>::try_from(val) {
+            Ok(v) => {
+                *ret = v.into();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    pub mod well_known {
+        use super::*;
+
+        macro_rules! well_known {
+            { $($name:ident => $getter:ident;)* } => {
+                $(
+                    pub unsafe extern "C" fn $name(scope: *mut HandleScopeOpaque) -> LocalSymbolOpaque {
+                        let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
+                        v8::Symbol::$getter(scope).into()
+                    }
+                )*
+            };
+        }
+
+        well_known! {
+            async_iterator => get_async_iterator;
+            has_instance => get_has_instance;
+            is_concat_spreadable => get_is_concat_spreadable;
+            iterator => get_iterator;
+            match_ => get_match;
+            replace => get_replace;
+            search => get_search;
+            split => get_split;
+            to_primitive => get_to_primitive;
+            to_string_tag => get_to_string_tag;
+            unscopables => get_unscopables;
+        }
+    }
+
+    pub unsafe extern "C" fn private_new(scope: *mut HandleScopeOpaque) -> LocalPrivateOpaque {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
+        v8::Private::new(scope, None).into()
+    }
+
+    pub unsafe extern "C" fn private_new_name(
+        scope: *mut HandleScopeOpaque,
+        name: LocalStringOpaque,
+    ) -> LocalPrivateOpaque {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
+        v8::Private::new(scope, Some(name.into())).into()
+    }
+
+    pub unsafe extern "C" fn private_for_key(
+        scope: *mut HandleScopeOpaque,
+        key: LocalStringOpaque,
+    ) -> LocalPrivateOpaque {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
+        v8::Private::for_api(scope, Some(key.into())).into()
+    }
+
+    pub unsafe extern "C" fn private_name(
+        ptr: LocalPrivateOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> LocalValueOpaque {
+        let key: v8::Local<'static, v8::Private> = ptr.into();
+        let scope = &mut *(scope as *mut v8::HandleScope<'static, ()>);
+        key.name(scope).into()
+    }
+}
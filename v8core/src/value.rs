@@ -1,5 +1,5 @@
-use crate::{impl_transmute_cast, HandleScopeOpaque, LocalStringOpaque};
-use std::{ops::Deref, ffi::c_void};
+use crate::{impl_transmute_cast, HandleScopeOpaque, LocalObjectOpaque, LocalStringOpaque};
+use std::{ffi::c_void, ops::Deref};
 
 #[repr(C)]
 pub struct ValueOpaque;
@@ -26,6 +26,9 @@ pub struct ValueVTable {
     is_name: unsafe extern "C" fn(ptr: *const ValueOpaque) -> bool,
     is_string: unsafe extern "C" fn(ptr: *const ValueOpaque) -> bool,
     is_symbol: unsafe extern "C" fn(ptr: *const ValueOpaque) -> bool,
+    is_object: unsafe extern "C" fn(ptr: *const ValueOpaque) -> bool,
+    is_date: unsafe extern "C" fn(ptr: *const ValueOpaque) -> bool,
+    is_reg_exp: unsafe extern "C" fn(ptr: *const ValueOpaque) -> bool,
 
     // cast
     to_string: unsafe extern "C" fn(
@@ -33,6 +36,11 @@ pub struct ValueVTable {
         scope: *mut HandleScopeOpaque,
         ret: *mut LocalStringOpaque,
     ) -> bool,
+    to_object: unsafe extern "C" fn(
+        ptr: *const ValueOpaque,
+        scope: *mut HandleScopeOpaque,
+        ret: *mut LocalObjectOpaque,
+    ) -> bool,
 }
 
 pub const VALUE_VTABLE: ValueVTable = ValueVTable {
@@ -48,9 +56,13 @@ pub const VALUE_VTABLE: ValueVTable = ValueVTable {
     is_name: impls::type_check::is_name,
     is_string: impls::type_check::is_string,
     is_symbol: impls::type_check::is_symbol,
+    is_object: impls::type_check::is_object,
+    is_date: impls::type_check::is_date,
+    is_reg_exp: impls::type_check::is_reg_exp,
 
     // cast
     to_string: impls::cast::to_string,
+    to_object: impls::cast::to_object,
 };
 
 mod impls {
@@ -114,6 +126,21 @@ mod impls {
             let val = &*(ptr as *const v8::Value);
             val.is_symbol()
         }
+
+        pub unsafe extern "C" fn is_object(ptr: *const ValueOpaque) -> bool {
+            let val = &*(ptr as *const v8::Value);
+            val.is_object()
+        }
+
+        pub unsafe extern "C" fn is_date(ptr: *const ValueOpaque) -> bool {
+            let val = &*(ptr as *const v8::Value);
+            val.is_date()
+        }
+
+        pub unsafe extern "C" fn is_reg_exp(ptr: *const ValueOpaque) -> bool {
+            let val = &*(ptr as *const v8::Value);
+            val.is_reg_exp()
+        }
     }
 
     pub mod cast {
@@ -135,5 +162,22 @@ mod impls {
                 None => false,
             }
         }
+
+        pub unsafe extern "C" fn to_object(
+            ptr: *const ValueOpaque,
+            scope: *mut HandleScopeOpaque,
+            ret: *mut LocalObjectOpaque,
+        ) -> bool {
+            let val = &*(ptr as *const v8::Value);
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            let r = val.to_object(scope);
+            match r {
+                Some(v) => {
+                    *ret = v.into();
+                    true
+                }
+                None => false,
+            }
+        }
     }
 }
@@ -0,0 +1,266 @@
+use crate::{
+    impl_transmute_cast, throw_not_supported, ByteSlice, HandleScopeOpaque, LocalObjectOpaque,
+    LocalValueOpaque, OptionBool,
+};
+use std::ffi::c_void;
+use v8::ValueDeserializerHelper;
+
+#[repr(C)]
+pub struct ValueDeserializerOpaque(usize);
+
+impl_transmute_cast! { ValueDeserializerOpaque as v8::ValueDeserializer<'static, 'static> }
+
+/// The deserializer itself or the one passed to `read_host_object`
+#[repr(C)]
+pub struct ValueDeserializerHelperOpaque(usize, usize);
+
+impl_transmute_cast! { ValueDeserializerHelperOpaque as &'static mut dyn ValueDeserializerHelper }
+
+/// Host callbacks used while deserializing, a null callback keeps v8's default of throwing an error
+#[repr(C)]
+pub struct ValueDeserializerDelegate {
+    data: *mut c_void,
+    read_host_object: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            scope: *mut HandleScopeOpaque,
+            deserializer: ValueDeserializerHelperOpaque,
+            ret: *mut LocalObjectOpaque,
+        ) -> bool,
+    >,
+    get_shared_array_buffer_from_id: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            scope: *mut HandleScopeOpaque,
+            id: u32,
+            ret: *mut LocalObjectOpaque,
+        ) -> bool,
+    >,
+}
+
+#[repr(C)]
+pub struct ValueDeserializerVTable {
+    ctor: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        delegate: *const ValueDeserializerDelegate,
+        data: ByteSlice,
+    ) -> ValueDeserializerOpaque,
+    drop: unsafe extern "C" fn(ptr: ValueDeserializerOpaque),
+    set_supports_legacy_wire_format:
+        unsafe extern "C" fn(ptr: *mut ValueDeserializerOpaque, supports: bool),
+    helper:
+        unsafe extern "C" fn(ptr: *mut ValueDeserializerOpaque) -> ValueDeserializerHelperOpaque,
+
+    // read
+    read_header: unsafe extern "C" fn(
+        helper: ValueDeserializerHelperOpaque,
+        scope: *mut HandleScopeOpaque,
+    ) -> OptionBool,
+    read_value: unsafe extern "C" fn(
+        helper: ValueDeserializerHelperOpaque,
+        scope: *mut HandleScopeOpaque,
+        ret: *mut LocalValueOpaque,
+    ) -> bool,
+    read_uint32: unsafe extern "C" fn(helper: ValueDeserializerHelperOpaque, ret: *mut u32) -> bool,
+    read_uint64: unsafe extern "C" fn(helper: ValueDeserializerHelperOpaque, ret: *mut u64) -> bool,
+    read_double: unsafe extern "C" fn(helper: ValueDeserializerHelperOpaque, ret: *mut f64) -> bool,
+    read_raw_bytes: unsafe extern "C" fn(
+        helper: ValueDeserializerHelperOpaque,
+        len: usize,
+        ret: *mut ByteSlice,
+    ) -> bool,
+}
+
+pub const VALUE_DESERIALIZER_VTABLE: ValueDeserializerVTable = ValueDeserializerVTable {
+    ctor: impls::value_deserializer_new,
+    drop: impls::value_deserializer_drop,
+    set_supports_legacy_wire_format: impls::value_deserializer_set_supports_legacy_wire_format,
+    helper: impls::value_deserializer_helper,
+
+    // read
+    read_header: impls::read::read_header,
+    read_value: impls::read::read_value,
+    read_uint32: impls::read::read_uint32,
+    read_uint64: impls::read::read_uint64,
+    read_double: impls::read::read_double,
+    read_raw_bytes: impls::read::read_raw_bytes,
+};
+
+struct HostValueDeserializer {
+    delegate: ValueDeserializerDelegate,
+}
+
+impl v8::ValueDeserializerImpl for HostValueDeserializer {
+    fn read_host_object<'s>(
+        &mut self,
+        scope: &mut v8::HandleScope<'s>,
+        value_deserializer: &mut dyn ValueDeserializerHelper,
+    ) -> Option<v8::Local<'s, v8::Object>> {
+        let callback = match self.delegate.read_host_object {
+            Some(callback) => callback,
+            None => return throw_not_supported(scope, "host objects cannot be deserialized"),
+        };
+        unsafe {
+            let deserializer: &'static mut dyn ValueDeserializerHelper =
+                std::mem::transmute(value_deserializer);
+            let mut ret = std::mem::MaybeUninit::<LocalObjectOpaque>::uninit();
+            let ok = callback(
+                self.delegate.data,
+                scope as *mut _ as *mut HandleScopeOpaque,
+                deserializer.into(),
+                ret.as_mut_ptr(),
+            );
+            if !ok {
+                return None;
+            }
+            let object: v8::Local<'static, v8::Object> = ret.assume_init().into();
+            Some(object)
+        }
+    }
+
+    fn get_shared_array_buffer_from_id<'s>(
+        &mut self,
+        scope: &mut v8::HandleScope<'s>,
+        transfer_id: u32,
+    ) -> Option<v8::Local<'s, v8::SharedArrayBuffer>> {
+        let callback = match self.delegate.get_shared_array_buffer_from_id {
+            Some(callback) => callback,
+            None => {
+                return throw_not_supported(scope, "shared array buffers cannot be deserialized")
+            }
+        };
+        let object: v8::Local<'static, v8::Object> = unsafe {
+            let mut ret = std::mem::MaybeUninit::<LocalObjectOpaque>::uninit();
+            let ok = callback(
+                self.delegate.data,
+                scope as *mut _ as *mut HandleScopeOpaque,
+                transfer_id,
+                ret.as_mut_ptr(),
+            );
+            if !ok {
+                return None;
+            }
+            ret.assume_init().into()
+        };
+        match v8::Local::<v8::SharedArrayBuffer>::try_from(object) {
+            Ok(v) => Some(v),
+            Err(_) => throw_not_supported(scope, "the host did not return a shared array buffer"),
+        }
+    }
+}
+
+mod impls {
+    use super::*;
+
+    /// `data` is borrowed, it and the delegate's `data` must stay valid until the deserializer is dropped
+    ///
+    /// # Safety
+    ///
+    /// The deserializer is transmuted to `'static`, it must be dropped before `scope` and the
+    /// context it was entered in are exited. The locals handed to the delegate callbacks are only
+    /// valid for the duration of that callback.
+    pub unsafe extern "C" fn value_deserializer_new(
+        scope: *mut HandleScopeOpaque,
+        delegate: *const ValueDeserializerDelegate,
+        data: ByteSlice,
+    ) -> ValueDeserializerOpaque {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        let delegate = std::ptr::read(delegate);
+        let deserializer =
+            v8::ValueDeserializer::new(scope, Box::new(HostValueDeserializer { delegate }), &data);
+        deserializer.into()
+    }
+
+    pub unsafe extern "C" fn value_deserializer_drop(ptr: ValueDeserializerOpaque) {
+        let deserializer: v8::ValueDeserializer<'static, 'static> = ptr.into();
+        drop(deserializer)
+    }
+
+    pub unsafe extern "C" fn value_deserializer_set_supports_legacy_wire_format(
+        ptr: *mut ValueDeserializerOpaque,
+        supports: bool,
+    ) {
+        let deserializer = &mut *(ptr as *mut v8::ValueDeserializer<'static, 'static>);
+        deserializer.set_supports_legacy_wire_format(supports)
+    }
+
+    /// The helper borrows the deserializer, it must not be used after the deserializer is moved
+    pub unsafe extern "C" fn value_deserializer_helper(
+        ptr: *mut ValueDeserializerOpaque,
+    ) -> ValueDeserializerHelperOpaque {
+        let deserializer = &mut *(ptr as *mut v8::ValueDeserializer<'static, 'static>);
+        let helper: &'static mut dyn ValueDeserializerHelper = deserializer;
+        helper.into()
+    }
+
+    pub mod read {
+        use super::*;
+
+        pub unsafe extern "C" fn read_header(
+            helper: ValueDeserializerHelperOpaque,
+            scope: *mut HandleScopeOpaque,
+        ) -> OptionBool {
+            let helper: &'static mut dyn ValueDeserializerHelper = helper.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            let context = scope.get_current_context();
+            helper.read_header(context).into()
+        }
+
+        pub unsafe extern "C" fn read_value(
+            helper: ValueDeserializerHelperOpaque,
+            scope: *mut HandleScopeOpaque,
+            ret: *mut LocalValueOpaque,
+        ) -> bool {
+            let helper: &'static mut dyn ValueDeserializerHelper = helper.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            let context = scope.get_current_context();
+            match helper.read_value(context) {
+                Some(v) => {
+                    *ret = v.into();
+                    true
+                }
+                None => false,
+            }
+        }
+
+        pub unsafe extern "C" fn read_uint32(
+            helper: ValueDeserializerHelperOpaque,
+            ret: *mut u32,
+        ) -> bool {
+            let helper: &'static mut dyn ValueDeserializerHelper = helper.into();
+            helper.read_uint32(&mut *ret)
+        }
+
+        pub unsafe extern "C" fn read_uint64(
+            helper: ValueDeserializerHelperOpaque,
+            ret: *mut u64,
+        ) -> bool {
+            let helper: &'static mut dyn ValueDeserializerHelper = helper.into();
+            helper.read_uint64(&mut *ret)
+        }
+
+        pub unsafe extern "C" fn read_double(
+            helper: ValueDeserializerHelperOpaque,
+            ret: *mut f64,
+        ) -> bool {
+            let helper: &'static mut dyn ValueDeserializerHelper = helper.into();
+            helper.read_double(&mut *ret)
+        }
+
+        /// The bytes point into the deserializer's data
+        pub unsafe extern "C" fn read_raw_bytes(
+            helper: ValueDeserializerHelperOpaque,
+            len: usize,
+            ret: *mut ByteSlice,
+        ) -> bool {
+            let helper: &'static mut dyn ValueDeserializerHelper = helper.into();
+            match helper.read_raw_bytes(len) {
+                Some(v) => {
+                    *ret = ByteSlice::new(v);
+                    true
+                }
+                None => false,
+            }
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{
+    impl_transmute_cast, ByteSlice, HandleScopeOpaque, LocalObjectOpaque, LocalStringOpaque,
+    LocalValueOpaque, OptionBool,
+};
+use std::ffi::c_void;
+use v8::ValueSerializerHelper;
+
+#[repr(C)]
+pub struct ValueSerializerOpaque(usize);
+
+impl_transmute_cast! { ValueSerializerOpaque as v8::ValueSerializer<'static, 'static> }
+
+/// The serializer itself or the one passed to `write_host_object`
+#[repr(C)]
+pub struct ValueSerializerHelperOpaque(usize, usize);
+
+impl_transmute_cast! { ValueSerializerHelperOpaque as &'static mut dyn ValueSerializerHelper }
+
+#[repr(C)]
+pub struct SerializedDataOpaque(usize, usize, usize);
+
+impl_transmute_cast! { SerializedDataOpaque as Vec<u8> }
+
+/// Host callbacks used while serializing, a null callback keeps v8's default of throwing an error
+#[repr(C)]
+pub struct ValueSerializerDelegate {
+    data: *mut c_void,
+    throw_data_clone_error: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            scope: *mut HandleScopeOpaque,
+            message: LocalStringOpaque,
+        ),
+    >,
+    write_host_object: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            scope: *mut HandleScopeOpaque,
+            object: LocalObjectOpaque,
+            serializer: ValueSerializerHelperOpaque,
+        ) -> OptionBool,
+    >,
+    get_shared_array_buffer_id: Option<
+        unsafe extern "C" fn(
+            data: *mut c_void,
+            scope: *mut HandleScopeOpaque,
+            shared_array_buffer: LocalObjectOpaque,
+            ret: *mut u32,
+        ) -> bool,
+    >,
+}
+
+#[repr(C)]
+pub struct ValueSerializerVTable {
+    ctor: unsafe extern "C" fn(
+        scope: *mut HandleScopeOpaque,
+        delegate: *const ValueSerializerDelegate,
+    ) -> ValueSerializerOpaque,
+    drop: unsafe extern "C" fn(ptr: ValueSerializerOpaque),
+    release: unsafe extern "C" fn(ptr: ValueSerializerOpaque) -> SerializedDataOpaque,
+    helper: unsafe extern "C" fn(ptr: *mut ValueSerializerOpaque) -> ValueSerializerHelperOpaque,
+
+    // write
+    write_header: unsafe extern "C" fn(helper: ValueSerializerHelperOpaque),
+    write_value: unsafe extern "C" fn(
+        helper: ValueSerializerHelperOpaque,
+        scope: *mut HandleScopeOpaque,
+        value: LocalValueOpaque,
+    ) -> OptionBool,
+    write_uint32: unsafe extern "C" fn(helper: ValueSerializerHelperOpaque, value: u32),
+    write_uint64: unsafe extern "C" fn(helper: ValueSerializerHelperOpaque, value: u64),
+    write_double: unsafe extern "C" fn(helper: ValueSerializerHelperOpaque, value: f64),
+    write_raw_bytes: unsafe extern "C" fn(helper: ValueSerializerHelperOpaque, bytes: ByteSlice),
+
+    // serialized data
+    data_drop: unsafe extern "C" fn(ptr: SerializedDataOpaque),
+    data_bytes: unsafe extern "C" fn(ptr: *const SerializedDataOpaque) -> ByteSlice,
+}
+
+pub const VALUE_SERIALIZER_VTABLE: ValueSerializerVTable = ValueSerializerVTable {
+    ctor: impls::value_serializer_new,
+    drop: impls::value_serializer_drop,
+    release: impls::value_serializer_release,
+    helper: impls::value_serializer_helper,
+
+    // write
+    write_header: impls::write::write_header,
+    write_value: impls::write::write_value,
+    write_uint32: impls::write::write_uint32,
+    write_uint64: impls::write::write_uint64,
+    write_double: impls::write::write_double,
+    write_raw_bytes: impls::write::write_raw_bytes,
+
+    // serialized data
+    data_drop: impls::serialized_data_drop,
+    data_bytes: impls::serialized_data_bytes,
+};
+
+struct HostValueSerializer {
+    delegate: ValueSerializerDelegate,
+}
+
+impl v8::ValueSerializerImpl for HostValueSerializer {
+    fn throw_data_clone_error<'s>(
+        &mut self,
+        scope: &mut v8::HandleScope<'s>,
+        message: v8::Local<'s, v8::String>,
+    ) {
+        match self.delegate.throw_data_clone_error {
+            Some(callback) => unsafe {
+                let message: v8::Local<'static, v8::String> = std::mem::transmute(message);
+                callback(
+                    self.delegate.data,
+                    scope as *mut _ as *mut HandleScopeOpaque,
+                    message.into(),
+                )
+            },
+            None => {
+                let exception = v8::Exception::error(scope, message);
+                scope.throw_exception(exception);
+            }
+        }
+    }
+
+    fn write_host_object<'s>(
+        &mut self,
+        scope: &mut v8::HandleScope<'s>,
+        object: v8::Local<'s, v8::Object>,
+        value_serializer: &mut dyn ValueSerializerHelper,
+    ) -> Option<bool> {
+        let callback = match self.delegate.write_host_object {
+            Some(callback) => callback,
+            None => return throw_not_supported(scope, "host objects cannot be serialized"),
+        };
+        unsafe {
+            let serializer: &'static mut dyn ValueSerializerHelper =
+                std::mem::transmute(value_serializer);
+            let object: v8::Local<'static, v8::Object> = std::mem::transmute(object);
+            callback(
+                self.delegate.data,
+                scope as *mut _ as *mut HandleScopeOpaque,
+                object.into(),
+                serializer.into(),
+            )
+            .into()
+        }
+    }
+
+    fn get_shared_array_buffer_id<'s>(
+        &mut self,
+        scope: &mut v8::HandleScope<'s>,
+        shared_array_buffer: v8::Local<'s, v8::SharedArrayBuffer>,
+    ) -> Option<u32> {
+        let callback = match self.delegate.get_shared_array_buffer_id {
+            Some(callback) => callback,
+            None => return throw_not_supported(scope, "shared array buffers cannot be serialized"),
+        };
+        let object: v8::Local<v8::Object> = shared_array_buffer.into();
+        let mut id = 0;
+        let ok = unsafe {
+            let object: v8::Local<'static, v8::Object> = std::mem::transmute(object);
+            callback(
+                self.delegate.data,
+                scope as *mut _ as *mut HandleScopeOpaque,
+                object.into(),
+                &mut id,
+            )
+        };
+        ok.then_some(id)
+    }
+}
+
+pub(crate) fn throw_not_supported<T>(scope: &mut v8::HandleScope, message: &str) -> Option<T> {
+    let message = v8::String::new(scope, message)?;
+    let exception = v8::Exception::error(scope, message);
+    scope.throw_exception(exception);
+    None
+}
+
+mod impls {
+    use super::*;
+
+    /// The delegate is copied, its `data` must stay valid until the serializer is dropped or released
+    ///
+    /// # Safety
+    ///
+    /// The serializer is transmuted to `'static`, it must be dropped or released before `scope`
+    /// and the context it was entered in are exited. The locals handed to the delegate callbacks
+    /// are only valid for the duration of that callback.
+    pub unsafe extern "C" fn value_serializer_new(
+        scope: *mut HandleScopeOpaque,
+        delegate: *const ValueSerializerDelegate,
+    ) -> ValueSerializerOpaque {
+        let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+        let delegate = std::ptr::read(delegate);
+        let serializer =
+            v8::ValueSerializer::new(scope, Box::new(HostValueSerializer { delegate }));
+        serializer.into()
+    }
+
+    pub unsafe extern "C" fn value_serializer_drop(ptr: ValueSerializerOpaque) {
+        let serializer: v8::ValueSerializer<'static, 'static> = ptr.into();
+        drop(serializer)
+    }
+
+    pub unsafe extern "C" fn value_serializer_release(
+        ptr: ValueSerializerOpaque,
+    ) -> SerializedDataOpaque {
+        let serializer: v8::ValueSerializer<'static, 'static> = ptr.into();
+        serializer.release().into()
+    }
+
+    /// The helper borrows the serializer, it must not be used after the serializer is moved
+    pub unsafe extern "C" fn value_serializer_helper(
+        ptr: *mut ValueSerializerOpaque,
+    ) -> ValueSerializerHelperOpaque {
+        let serializer = &mut *(ptr as *mut v8::ValueSerializer<'static, 'static>);
+        let helper: &'static mut dyn ValueSerializerHelper = serializer;
+        helper.into()
+    }
+
+    pub mod write {
+        use super::*;
+
+        pub unsafe extern "C" fn write_header(helper: ValueSerializerHelperOpaque) {
+            let helper: &'static mut dyn ValueSerializerHelper = helper.into();
+            helper.write_header()
+        }
+
+        pub unsafe extern "C" fn write_value(
+            helper: ValueSerializerHelperOpaque,
+            scope: *mut HandleScopeOpaque,
+            value: LocalValueOpaque,
+        ) -> OptionBool {
+            let helper: &'static mut dyn ValueSerializerHelper = helper.into();
+            let scope = &mut *(scope as *mut v8::HandleScope<'static>);
+            let context = scope.get_current_context();
+            helper.write_value(context, value.into()).into()
+        }
+
+        pub unsafe extern "C" fn write_uint32(helper: ValueSerializerHelperOpaque, value: u32) {
+            let helper: &'static mut dyn ValueSerializerHelper = helper.into();
+            helper.write_uint32(value)
+        }
+
+        pub unsafe extern "C" fn write_uint64(helper: ValueSerializerHelperOpaque, value: u64) {
+            let helper: &'static mut dyn ValueSerializerHelper = helper.into();
+            helper.write_uint64(value)
+        }
+
+        pub unsafe extern "C" fn write_double(helper: ValueSerializerHelperOpaque, value: f64) {
+            let helper: &'static mut dyn ValueSerializerHelper = helper.into();
+            helper.write_double(value)
+        }
+
+        pub unsafe extern "C" fn write_raw_bytes(
+            helper: ValueSerializerHelperOpaque,
+            bytes: ByteSlice,
+        ) {
+            let helper: &'static mut dyn ValueSerializerHelper = helper.into();
+            helper.write_raw_bytes(&bytes)
+        }
+    }
+
+    pub unsafe extern "C" fn serialized_data_drop(ptr: SerializedDataOpaque) {
+        let data: Vec<u8> = ptr.into();
+        drop(data)
+    }
+
+    pub unsafe extern "C" fn serialized_data_bytes(ptr: *const SerializedDataOpaque) -> ByteSlice {
+        let data = &*(ptr as *const Vec<u8>);
+        ByteSlice::new(data)
+    }
+}